@@ -4,42 +4,98 @@
 extern crate alloc;
 
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 use casper_contract::{
     self,
-    contract_api::{runtime, storage},
+    contract_api::{runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+    bytesrepr::ToBytes, contracts::NamedKeys, runtime_args, CLTyped, ContractHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
 };
 
 const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
+const CHECK_TOTAL_MINTED_ENTRY_POINT_NAME: &str = "check_total_minted";
+const CHECK_TOTAL_BURNED_ENTRY_POINT_NAME: &str = "check_total_burned";
 const CHECK_BALANCE_OF_ENTRY_POINT_NAME: &str = "check_balance_of";
 const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_contract";
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "check_minter_allowance";
+const CHECK_REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME: &str = "check_remaining_daily_limit";
+const CHECK_BALANCE_ON_HOLD_ENTRY_POINT_NAME: &str = "check_balance_on_hold";
+const CHECK_STREAM_BALANCE_ENTRY_POINT_NAME: &str = "check_stream_balance";
+const CHECK_WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME: &str = "check_withdrawable_dividend_of";
+const CHECK_CONVERT_TO_ASSETS_ENTRY_POINT_NAME: &str = "check_convert_to_assets";
+const CHECK_TOKEN_METADATA_ENTRY_POINT_NAME: &str = "check_token_metadata";
+const CHECK_TOKEN_INFO_ENTRY_POINT_NAME: &str = "check_token_info";
+const CHECK_BALANCES_OF_ENTRY_POINT_NAME: &str = "check_balances_of";
+const CHECK_ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "check_allowances_of";
+const CHECK_HOLDER_COUNT_ENTRY_POINT_NAME: &str = "check_holder_count";
+const CHECK_HOLDERS_ENTRY_POINT_NAME: &str = "check_holders";
+const DEPOSIT_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "deposit_as_stored_contract";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const MINTER_RUNTIME_ARG_NAME: &str = "minter";
 const RESULT_KEY: &str = "result";
 const CEP18_TEST_CALL_KEY: &str = "cep18_test_contract";
+const PURSE_KEY: &str = "purse";
+const STREAM_ID_RUNTIME_ARG_NAME: &str = "stream_id";
+const SHARES_RUNTIME_ARG_NAME: &str = "shares";
 
 const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
 const RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
 const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+const TOTAL_MINTED_ENTRY_POINT_NAME: &str = "total_minted";
+const TOTAL_BURNED_ENTRY_POINT_NAME: &str = "total_burned";
 const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+const MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "minter_allowance";
+const REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME: &str = "remaining_daily_limit";
+const BALANCE_ON_HOLD_ENTRY_POINT_NAME: &str = "balance_on_hold";
+const STREAM_BALANCE_ENTRY_POINT_NAME: &str = "stream_balance";
+const WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME: &str = "withdrawable_dividend_of";
+const CONVERT_TO_ASSETS_ENTRY_POINT_NAME: &str = "convert_to_assets";
+const TOKEN_METADATA_ENTRY_POINT_NAME: &str = "token_metadata";
+const TOKEN_INFO_ENTRY_POINT_NAME: &str = "token_info";
+const BALANCES_OF_ENTRY_POINT_NAME: &str = "balances_of";
+const ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "allowances_of";
+const ADDRESSES_RUNTIME_ARG_NAME: &str = "addresses";
+const PAIRS_RUNTIME_ARG_NAME: &str = "pairs";
+const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+const HOLDERS_ENTRY_POINT_NAME: &str = "holders";
+const START_RUNTIME_ARG_NAME: &str = "start";
+const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+const DEPOSIT_ENTRY_POINT_NAME: &str = "deposit";
+const PURSE_RUNTIME_ARG_NAME: &str = "purse";
+
+/// The value returned by the `token_info` entry point, whose fields are grouped into nested tuples.
+type TokenInfo = (
+    u32,
+    (
+        (String, String, u8),
+        U256,
+        (Option<String>, Option<String>, Option<String>),
+    ),
+    (
+        ((u8, u8, u8), (u8, u8, u8), (u8, u8, u8)),
+        (u8, String),
+        ((u8, u8), (u8, u8)),
+    ),
+);
 
 fn store_result<T: CLTyped + ToBytes>(result: T) {
     match runtime::get_key(RESULT_KEY) {
@@ -67,6 +123,36 @@ extern "C" fn check_total_supply() {
     store_result(total_supply);
 }
 
+#[no_mangle]
+extern "C" fn check_total_minted() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let total_minted: U256 = runtime::call_contract(
+        token_contract,
+        TOTAL_MINTED_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+    store_result(total_minted);
+}
+
+#[no_mangle]
+extern "C" fn check_total_burned() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let total_burned: U256 = runtime::call_contract(
+        token_contract,
+        TOTAL_BURNED_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+    store_result(total_burned);
+}
+
 #[no_mangle]
 extern "C" fn check_balance_of() {
     let token_contract: ContractHash = ContractHash::new(
@@ -105,6 +191,244 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_balances_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let addresses: Vec<Key> = runtime::get_named_arg(ADDRESSES_RUNTIME_ARG_NAME);
+
+    let balances_of_args = runtime_args! {
+        ADDRESSES_RUNTIME_ARG_NAME => addresses,
+    };
+    let result: Vec<U256> = runtime::call_contract(
+        token_contract,
+        BALANCES_OF_ENTRY_POINT_NAME,
+        balances_of_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_allowances_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let pairs: Vec<(Key, Key)> = runtime::get_named_arg(PAIRS_RUNTIME_ARG_NAME);
+
+    let allowances_of_args = runtime_args! {
+        PAIRS_RUNTIME_ARG_NAME => pairs,
+    };
+    let result: Vec<U256> = runtime::call_contract(
+        token_contract,
+        ALLOWANCES_OF_ENTRY_POINT_NAME,
+        allowances_of_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holder_count() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let result: u64 = runtime::call_contract(
+        token_contract,
+        HOLDER_COUNT_ENTRY_POINT_NAME,
+        RuntimeArgs::new(),
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holders() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let start: u64 = runtime::get_named_arg(START_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let holders_args = runtime_args! {
+        START_RUNTIME_ARG_NAME => start,
+        LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    let result: Vec<Key> =
+        runtime::call_contract(token_contract, HOLDERS_ENTRY_POINT_NAME, holders_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_minter_allowance() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let minter: Key = runtime::get_named_arg(MINTER_RUNTIME_ARG_NAME);
+
+    let minter_allowance_args = runtime_args! {
+        MINTER_RUNTIME_ARG_NAME => minter,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        MINTER_ALLOWANCE_ENTRY_POINT_NAME,
+        minter_allowance_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_remaining_daily_limit() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let remaining_daily_limit_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME,
+        remaining_daily_limit_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_balance_on_hold() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let balance_on_hold_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        BALANCE_ON_HOLD_ENTRY_POINT_NAME,
+        balance_on_hold_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_stream_balance() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID_RUNTIME_ARG_NAME);
+
+    let stream_balance_args = runtime_args! {
+        STREAM_ID_RUNTIME_ARG_NAME => stream_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        STREAM_BALANCE_ENTRY_POINT_NAME,
+        stream_balance_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_withdrawable_dividend_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let withdrawable_dividend_of_args = runtime_args! {
+        ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME,
+        withdrawable_dividend_of_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_convert_to_assets() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let shares: U256 = runtime::get_named_arg(SHARES_RUNTIME_ARG_NAME);
+
+    let convert_to_assets_args = runtime_args! {
+        SHARES_RUNTIME_ARG_NAME => shares,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        CONVERT_TO_ASSETS_ENTRY_POINT_NAME,
+        convert_to_assets_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_token_metadata() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    let result: BTreeMap<String, String> = runtime::call_contract(
+        token_contract,
+        TOKEN_METADATA_ENTRY_POINT_NAME,
+        RuntimeArgs::new(),
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_token_info() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    let result: TokenInfo = runtime::call_contract(
+        token_contract,
+        TOKEN_INFO_ENTRY_POINT_NAME,
+        RuntimeArgs::new(),
+    );
+
+    store_result(result);
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -165,6 +489,26 @@ extern "C" fn approve_as_stored_contract() {
     runtime::call_contract::<()>(token_contract, APPROVE_ENTRY_POINT_NAME, approve_args);
 }
 
+#[no_mangle]
+extern "C" fn deposit_as_stored_contract() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let purse = runtime::get_key(PURSE_KEY)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert();
+
+    let deposit_args = runtime_args! {
+        PURSE_RUNTIME_ARG_NAME => purse,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+    };
+
+    runtime::call_contract::<()>(token_contract, DEPOSIT_ENTRY_POINT_NAME, deposit_args);
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
     let mut entry_points = EntryPoints::new();
@@ -178,6 +522,26 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_total_minted_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_MINTED_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_total_burned_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_BURNED_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let check_balance_of_entrypoint = EntryPoint::new(
         String::from(CHECK_BALANCE_OF_ENTRY_POINT_NAME),
         vec![
@@ -199,6 +563,136 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_minter_allowance_entrypoint = EntryPoint::new(
+        String::from(CHECK_MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(MINTER_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_remaining_daily_limit_entrypoint = EntryPoint::new(
+        String::from(CHECK_REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_balance_on_hold_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCE_ON_HOLD_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_stream_balance_entrypoint = EntryPoint::new(
+        String::from(CHECK_STREAM_BALANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(STREAM_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_withdrawable_dividend_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_convert_to_assets_entrypoint = EntryPoint::new(
+        String::from(CHECK_CONVERT_TO_ASSETS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SHARES_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_token_metadata_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOKEN_METADATA_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_token_info_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOKEN_INFO_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_balances_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCES_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESSES_RUNTIME_ARG_NAME, Vec::<Key>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_allowances_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_ALLOWANCES_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(PAIRS_RUNTIME_ARG_NAME, Vec::<(Key, Key)>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_holder_count_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDER_COUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            TOKEN_CONTRACT_RUNTIME_ARG_NAME,
+            ContractHash::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_holders_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(START_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
 
     let transfer_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
@@ -237,16 +731,45 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let deposit_as_stored_contract_entrypoint = EntryPoint::new(
+        String::from(DEPOSIT_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     entry_points.add_entry_point(check_total_supply_entrypoint);
+    entry_points.add_entry_point(check_total_minted_entrypoint);
+    entry_points.add_entry_point(check_total_burned_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
+    entry_points.add_entry_point(check_minter_allowance_entrypoint);
+    entry_points.add_entry_point(check_remaining_daily_limit_entrypoint);
+    entry_points.add_entry_point(check_balance_on_hold_entrypoint);
+    entry_points.add_entry_point(check_stream_balance_entrypoint);
+    entry_points.add_entry_point(check_withdrawable_dividend_of_entrypoint);
+    entry_points.add_entry_point(check_convert_to_assets_entrypoint);
+    entry_points.add_entry_point(check_token_metadata_entrypoint);
+    entry_points.add_entry_point(check_token_info_entrypoint);
+    entry_points.add_entry_point(check_balances_of_entrypoint);
+    entry_points.add_entry_point(check_allowances_of_entrypoint);
+    entry_points.add_entry_point(check_holder_count_entrypoint);
+    entry_points.add_entry_point(check_holders_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(deposit_as_stored_contract_entrypoint);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(PURSE_KEY.to_string(), system::create_purse().into());
 
     let (_contract_hash, _version) = storage::new_contract(
         entry_points,
-        None,
+        Some(named_keys),
         Some(CEP18_TEST_CALL_KEY.to_string()),
         None,
     );
@@ -0,0 +1,131 @@
+//! Implementation of Merkle airdrops, in which recipients claim their tokens with a proof against
+//! a Merkle root set by an admin, instead of being sent them one by one.
+//!
+//! Each leaf of the tree commits to an `(index, account, amount)` triple as
+//! `blake2b(0x00 || bytes)`, and each inner node to its two children in ascending order as
+//! `blake2b(0x01 || left || right)`, so proofs need no position bits. Claimed indices are recorded
+//! in a bitmap of 256 indices per dictionary item, keyed by the airdrop epoch, which increases
+//! with every new root. In the `MintBurn` mode claims are minted; otherwise the tokens are
+//! escrowed under the contract's own package key when the root is set, and unclaimed tokens of a
+//! previous airdrop roll over to the next one.
+use alloc::{format, string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{self, dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+
+use crate::{
+    balances::transfer_balance,
+    constants::{AIRDROP_CLAIMED, AIRDROP_EPOCH, AIRDROP_REMAINING, ENABLE_MINT_BURN, MERKLE_ROOT},
+    error::Cep18Error,
+    rebasing,
+    utils::{self, get_uref, read_from},
+};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+const BITMAP_WORD_BITS: u64 = 256;
+
+/// Returns `true` if claims are minted rather than paid out of escrow.
+pub(crate) fn is_minting() -> bool {
+    read_from::<u8>(ENABLE_MINT_BURN) != 0
+}
+
+fn hash_leaf(index: u64, account: Key, amount: U256) -> Result<[u8; 32], Cep18Error> {
+    let mut bytes = Vec::from([LEAF_PREFIX]);
+    bytes.append(
+        &mut (index, account, amount)
+            .to_bytes()
+            .map_err(|_| Cep18Error::InvalidMerkleProof)?,
+    );
+    Ok(runtime::blake2b(bytes))
+}
+
+fn hash_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut bytes = Vec::from([NODE_PREFIX]);
+    bytes.extend_from_slice(&left);
+    bytes.extend_from_slice(&right);
+    runtime::blake2b(bytes)
+}
+
+fn bitmap_item_key(index: u64) -> String {
+    format!(
+        "{}_{}",
+        read_from::<u64>(AIRDROP_EPOCH),
+        index / BITMAP_WORD_BITS
+    )
+}
+
+/// Sets a new Merkle root for `total` tokens and starts a new airdrop epoch. Outside the
+/// `MintBurn` mode, `admin` tops up or is refunded the difference to the tokens still escrowed,
+/// so it cannot be the contract itself, as in the admin council mode: the escrow would not be
+/// funded and claims would be paid out of other tokens escrowed under the package key. The escrow
+/// is not available in the rebasing mode, as it is held in shares while the claims are in tokens.
+pub(crate) fn set_merkle_root(admin: Key, root: [u8; 32], total: U256) -> Result<(), Cep18Error> {
+    if !is_minting() {
+        if admin == utils::get_self_key() {
+            return Err(Cep18Error::AirdropEscrowFromSelf);
+        }
+        if rebasing::is_rebasing_enabled() {
+            return Err(Cep18Error::AirdropWithRebasing);
+        }
+        let remaining: U256 = read_from(AIRDROP_REMAINING);
+        if total > remaining {
+            transfer_balance(admin, utils::get_self_key(), total - remaining)?;
+        } else {
+            transfer_balance(utils::get_self_key(), admin, remaining - total)?;
+        }
+    }
+    storage::write(get_uref(MERKLE_ROOT), Some(root));
+    storage::write(get_uref(AIRDROP_REMAINING), total);
+    storage::write(get_uref(AIRDROP_EPOCH), read_from::<u64>(AIRDROP_EPOCH) + 1);
+    Ok(())
+}
+
+fn read_bitmap_word(item_key: &str) -> U256 {
+    dictionary_get(get_uref(AIRDROP_CLAIMED), item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Verifies the claim of `amount` tokens by `account` at leaf `index` against the current root and
+/// marks it as claimed. The caller pays out the tokens.
+pub(crate) fn claim(
+    index: u64,
+    account: Key,
+    amount: U256,
+    proof: &[[u8; 32]],
+) -> Result<(), Cep18Error> {
+    let root = read_from::<Option<[u8; 32]>>(MERKLE_ROOT).ok_or(Cep18Error::AirdropNotActive)?;
+    let computed_root = proof
+        .iter()
+        .fold(hash_leaf(index, account, amount)?, |hash, sibling| {
+            hash_node(hash, *sibling)
+        });
+    if computed_root != root {
+        return Err(Cep18Error::InvalidMerkleProof);
+    }
+    let item_key = bitmap_item_key(index);
+    let word = read_bitmap_word(&item_key);
+    let bit = (index % BITMAP_WORD_BITS) as usize;
+    if word.bit(bit) {
+        return Err(Cep18Error::AirdropAlreadyClaimed);
+    }
+    let remaining = read_from::<U256>(AIRDROP_REMAINING)
+        .checked_sub(amount)
+        .ok_or(Cep18Error::AirdropExhausted)?;
+
+    dictionary_put(
+        get_uref(AIRDROP_CLAIMED),
+        &item_key,
+        word | (U256::one() << bit),
+    );
+    storage::write(get_uref(AIRDROP_REMAINING), remaining);
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Implementation of transfers with authorization, modelled on EIP-3009, which let an owner sign a
+//! transfer off-chain and a relayer submit it on-chain and pay for its gas.
+//!
+//! An authorization signs `blake2b(bytes)`, where `bytes` is the serialized
+//! `(package_hash, entry_point, from, ...)` tuple of the arguments of the entry point it is meant
+//! for, so that it can neither be replayed against another token nor used for the other entry
+//! point. Authorizations are identified by random 32-byte nonces, each of which can be used or
+//! cancelled once per owner.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, PublicKey, U256};
+
+use crate::{
+    constants::{
+        AUTHORIZATION_STATES, CANCEL_AUTHORIZATION_ENTRY_POINT_NAME,
+        TRANSFER_WITH_AUTHORIZATION_ENTRY_POINT_NAME,
+    },
+    error::Cep18Error,
+    utils::{self, get_uref},
+};
+
+fn make_nonce_item_key(owner: Key, nonce: [u8; 32]) -> String {
+    let mut preimage: Vec<u8> = owner.to_bytes().unwrap_or_revert();
+    preimage.extend_from_slice(&nonce);
+    hex::encode(runtime::blake2b(preimage))
+}
+
+/// Checks that `signature` over `bytes` was made by `from` and marks `nonce` as used for `from`.
+fn use_nonce(
+    from: Key,
+    nonce: [u8; 32],
+    bytes: Vec<u8>,
+    signer_public_key: &PublicKey,
+    signature: &[u8],
+) -> Result<(), Cep18Error> {
+    if from != Key::Account(signer_public_key.to_account_hash())
+        || !utils::verify_signature(runtime::blake2b(bytes), signer_public_key, signature)
+    {
+        return Err(Cep18Error::InvalidAuthorizationSignature);
+    }
+    let states_uref = get_uref(AUTHORIZATION_STATES);
+    let item_key = make_nonce_item_key(from, nonce);
+    if dictionary_get::<bool>(states_uref, &item_key)
+        .unwrap_or_revert()
+        .is_some()
+    {
+        return Err(Cep18Error::AuthorizationAlreadyUsed);
+    }
+    dictionary_put(states_uref, &item_key, true);
+    Ok(())
+}
+
+/// Verifies an authorization signed by `from` to transfer `amount` tokens to `to` and marks its
+/// nonce as used. The caller transfers the tokens.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn use_transfer_authorization(
+    from: Key,
+    to: Key,
+    amount: U256,
+    valid_after: u64,
+    valid_before: u64,
+    nonce: [u8; 32],
+    signer_public_key: &PublicKey,
+    signature: &[u8],
+) -> Result<(), Cep18Error> {
+    let now = u64::from(runtime::get_blocktime());
+    if now <= valid_after {
+        return Err(Cep18Error::AuthorizationNotYetValid);
+    }
+    if now >= valid_before {
+        return Err(Cep18Error::AuthorizationExpired);
+    }
+    let bytes = (
+        utils::get_self_key(),
+        TRANSFER_WITH_AUTHORIZATION_ENTRY_POINT_NAME,
+        from,
+        to,
+        amount,
+        valid_after,
+        valid_before,
+        nonce,
+    )
+        .to_bytes()
+        .unwrap_or_revert();
+    use_nonce(from, nonce, bytes, signer_public_key, signature)
+}
+
+/// Verifies the cancellation of the authorization of `from` with the given `nonce` and marks the
+/// nonce as used, so that the authorization can no longer be used.
+pub(crate) fn cancel_authorization(
+    from: Key,
+    nonce: [u8; 32],
+    signer_public_key: &PublicKey,
+    signature: &[u8],
+) -> Result<(), Cep18Error> {
+    let bytes = (
+        utils::get_self_key(),
+        CANCEL_AUTHORIZATION_ENTRY_POINT_NAME,
+        from,
+        nonce,
+    )
+        .to_bytes()
+        .unwrap_or_revert();
+    use_nonce(from, nonce, bytes, signer_public_key, signature)
+}
@@ -1,24 +1,16 @@
 //! Implementation of balances.
-use alloc::string::String;
 
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
+use casper_types::{Key, URef, U256};
 
-use crate::{constants::BALANCES, error::Cep18Error, utils};
-
-/// Creates a dictionary item key for a dictionary item, by base64 encoding the Key argument
-/// since stringified Keys are too long to be used as dictionary keys.
-#[inline]
-fn make_dictionary_item_key(owner: Key) -> String {
-    let preimage = owner.to_bytes().unwrap_or_revert();
-    // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
-    // hashing (which will effectively hash a hash) we'll use base64. Preimage is 33 bytes for
-    // both used Key variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
-    // characters.
-    // Even if the preimage increased in size we still have extra space but even in case of much
-    // larger preimage we can switch to base85 which has ratio of 4:5.
-    base64::encode(preimage)
-}
+use crate::{
+    constants::BALANCES,
+    dividends,
+    error::Cep18Error,
+    holders, holds, max_balance,
+    rebasing::{self, Rounding},
+    transfer_limits, utils,
+};
 
 /// Getter for the "balances" dictionary URef.
 pub(crate) fn get_balances_uref() -> URef {
@@ -26,16 +18,20 @@ pub(crate) fn get_balances_uref() -> URef {
 }
 
 /// Writes token balance of a specified account into a dictionary.
+///
+/// In the rebasing mode balances are stored as shares, see [`rebasing`]. If the holder registry is
+/// enabled it is kept up to date, see [`holders`].
 pub(crate) fn write_balance_to(balances_uref: URef, address: Key, amount: U256) {
-    let dictionary_item_key = make_dictionary_item_key(address);
+    let dictionary_item_key = utils::make_dictionary_item_key(address);
     storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
+    holders::update_holder(address, amount);
 }
 
 /// Reads token balance of a specified account.
 ///
 /// If a given account does not have balances in the system, then a 0 is returned.
 pub(crate) fn read_balance_from(balances_uref: URef, address: Key) -> U256 {
-    let dictionary_item_key = make_dictionary_item_key(address);
+    let dictionary_item_key = utils::make_dictionary_item_key(address);
 
     storage::dictionary_get(balances_uref, &dictionary_item_key)
         .unwrap_or_revert()
@@ -44,6 +40,11 @@ pub(crate) fn read_balance_from(balances_uref: URef, address: Key) -> U256 {
 
 /// Transfer tokens from the `sender` to the `recipient`.
 ///
+/// Tokens of the `sender` that are on hold cannot be transferred. In the rebasing mode the amount
+/// is converted into shares, rounding up. In the transfer limits
+/// mode the amount counts towards the daily limit of the `sender`. In the maximum balance mode the
+/// new balance of the `recipient` must not exceed the maximum balance.
+///
 /// This function should not be used directly by contract's entrypoint as it does not validate the
 /// sender.
 pub(crate) fn transfer_balance(
@@ -55,20 +56,24 @@ pub(crate) fn transfer_balance(
         return Ok(());
     }
 
+    transfer_limits::record_transfer(sender, amount)?;
+    let shares = rebasing::amount_to_shares(amount, Rounding::Up)?;
+
     let balances_uref = get_balances_uref();
-    let new_sender_balance = {
-        let sender_balance = read_balance_from(balances_uref, sender);
-        sender_balance
-            .checked_sub(amount)
-            .ok_or(Cep18Error::InsufficientBalance)?
-    };
+    let sender_balance = read_balance_from(balances_uref, sender);
+    let new_sender_balance = sender_balance
+        .checked_sub(shares)
+        .ok_or(Cep18Error::InsufficientBalance)?;
+    holds::check_spendable(sender, new_sender_balance)?;
+
+    let recipient_balance = read_balance_from(balances_uref, recipient);
+    let new_recipient_balance = recipient_balance
+        .checked_add(shares)
+        .ok_or(Cep18Error::Overflow)?;
+    max_balance::check_max_balance(recipient, new_recipient_balance)?;
 
-    let new_recipient_balance = {
-        let recipient_balance = read_balance_from(balances_uref, recipient);
-        recipient_balance
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)?
-    };
+    dividends::update_correction(sender, sender_balance, new_sender_balance)?;
+    dividends::update_correction(recipient, recipient_balance, new_recipient_balance)?;
 
     write_balance_to(balances_uref, sender, new_sender_balance);
     write_balance_to(balances_uref, recipient, new_recipient_balance);
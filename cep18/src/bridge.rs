@@ -0,0 +1,24 @@
+//! Implementation of the processed external references used by the bridge entry points.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{constants::PROCESSED_REFS, error::Cep18Error, utils};
+
+#[inline]
+fn get_processed_refs_uref() -> URef {
+    utils::get_uref(PROCESSED_REFS)
+}
+
+/// Marks `external_ref` as processed, failing if it has been processed before.
+pub(crate) fn record_external_ref(external_ref: &str) -> Result<(), Cep18Error> {
+    let processed_refs_uref = get_processed_refs_uref();
+    let dictionary_item_key = utils::make_string_dictionary_item_key(external_ref);
+    let processed: bool = storage::dictionary_get(processed_refs_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    if processed {
+        return Err(Cep18Error::ExternalRefAlreadyProcessed);
+    }
+    storage::dictionary_put(processed_refs_uref, &dictionary_item_key, true);
+    Ok(())
+}
@@ -12,6 +12,10 @@ pub const BALANCES: &str = "balances";
 pub const ALLOWANCES: &str = "allowances";
 /// Name of named-key for `total_supply`
 pub const TOTAL_SUPPLY: &str = "total_supply";
+/// Name of named-key for `total_minted`
+pub const TOTAL_MINTED: &str = "total_minted";
+/// Name of named-key for `total_burned`
+pub const TOTAL_BURNED: &str = "total_burned";
 
 pub const HASH_KEY_NAME_PREFIX: &str = "cep18_contract_package_";
 pub const ACCESS_KEY_NAME_PREFIX: &str = "cep18_contract_package_access_";
@@ -24,6 +28,12 @@ pub const NAME_ENTRY_POINT_NAME: &str = "name";
 pub const SYMBOL_ENTRY_POINT_NAME: &str = "symbol";
 /// Name of `decimals` entry point.
 pub const DECIMALS_ENTRY_POINT_NAME: &str = "decimals";
+/// Name of `token_metadata` entry point.
+pub const TOKEN_METADATA_ENTRY_POINT_NAME: &str = "token_metadata";
+/// Name of `set_metadata` entry point.
+pub const SET_METADATA_ENTRY_POINT_NAME: &str = "set_metadata";
+/// Name of `token_info` entry point.
+pub const TOKEN_INFO_ENTRY_POINT_NAME: &str = "token_info";
 /// Name of `balance_of` entry point.
 pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 /// Name of `transfer` entry point.
@@ -32,10 +42,24 @@ pub const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 /// Name of `allowance` entry point.
 pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
+/// Name of `balances_of` entry point.
+pub const BALANCES_OF_ENTRY_POINT_NAME: &str = "balances_of";
+/// Name of `allowances_of` entry point.
+pub const ALLOWANCES_OF_ENTRY_POINT_NAME: &str = "allowances_of";
+/// Name of `holder_count` entry point.
+pub const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+/// Name of `holders` entry point.
+pub const HOLDERS_ENTRY_POINT_NAME: &str = "holders";
 /// Name of `transfer_from` entry point.
 pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 /// Name of `total_supply` entry point.
 pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+/// Name of `total_minted` entry point.
+pub const TOTAL_MINTED_ENTRY_POINT_NAME: &str = "total_minted";
+/// Name of `total_burned` entry point.
+pub const TOTAL_BURNED_ENTRY_POINT_NAME: &str = "total_burned";
+/// Name of `audit_supply` entry point.
+pub const AUDIT_SUPPLY_ENTRY_POINT_NAME: &str = "audit_supply";
 /// Name of `transfer_from` entry point.
 pub const MINT_ENTRY_POINT_NAME: &str = "mint";
 /// Name of `burn` entry point.
@@ -45,6 +69,115 @@ pub const INIT_ENTRY_POINT_NAME: &str = "init";
 /// Name of `change_security` entry point.
 pub const CHANGE_SECURITY_ENTRY_POINT_NAME: &str = "change_security";
 
+/// Name of `transfer_with_memo` entry point.
+pub const TRANSFER_WITH_MEMO_ENTRY_POINT_NAME: &str = "transfer_with_memo";
+/// Name of `transfer_from_with_memo` entry point.
+pub const TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME: &str = "transfer_from_with_memo";
+
+/// Name of `transfer_with_authorization` entry point.
+pub const TRANSFER_WITH_AUTHORIZATION_ENTRY_POINT_NAME: &str = "transfer_with_authorization";
+/// Name of `cancel_authorization` entry point.
+pub const CANCEL_AUTHORIZATION_ENTRY_POINT_NAME: &str = "cancel_authorization";
+
+/// Name of `change_transfer_fee` entry point.
+pub const CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME: &str = "change_transfer_fee";
+
+/// Name of `rebase` entry point.
+pub const REBASE_ENTRY_POINT_NAME: &str = "rebase";
+
+/// Name of `deposit` entry point.
+pub const DEPOSIT_ENTRY_POINT_NAME: &str = "deposit";
+/// Name of `withdraw` entry point.
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+
+/// Name of `wrap` entry point.
+pub const WRAP_ENTRY_POINT_NAME: &str = "wrap";
+/// Name of `unwrap` entry point.
+pub const UNWRAP_ENTRY_POINT_NAME: &str = "unwrap";
+
+/// Name of `migrate_from_legacy` entry point.
+pub const MIGRATE_FROM_LEGACY_ENTRY_POINT_NAME: &str = "migrate_from_legacy";
+/// Name of `close_legacy_migration` entry point.
+pub const CLOSE_LEGACY_MIGRATION_ENTRY_POINT_NAME: &str = "close_legacy_migration";
+
+/// Name of `create_proposal` entry point.
+pub const CREATE_PROPOSAL_ENTRY_POINT_NAME: &str = "create_proposal";
+/// Name of `approve_proposal` entry point.
+pub const APPROVE_PROPOSAL_ENTRY_POINT_NAME: &str = "approve_proposal";
+/// Name of `execute_proposal` entry point.
+pub const EXECUTE_PROPOSAL_ENTRY_POINT_NAME: &str = "execute_proposal";
+/// Name of `change_council` entry point.
+pub const CHANGE_COUNCIL_ENTRY_POINT_NAME: &str = "change_council";
+
+/// Name of `execute_scheduled` entry point.
+pub const EXECUTE_SCHEDULED_ENTRY_POINT_NAME: &str = "execute_scheduled";
+/// Name of `cancel_scheduled` entry point.
+pub const CANCEL_SCHEDULED_ENTRY_POINT_NAME: &str = "cancel_scheduled";
+
+/// Name of `set_merkle_root` entry point.
+pub const SET_MERKLE_ROOT_ENTRY_POINT_NAME: &str = "set_merkle_root";
+/// Name of `claim` entry point.
+pub const CLAIM_ENTRY_POINT_NAME: &str = "claim";
+
+/// Name of `mint_with_voucher` entry point.
+pub const MINT_WITH_VOUCHER_ENTRY_POINT_NAME: &str = "mint_with_voucher";
+
+/// Name of `mint_shares` entry point.
+pub const MINT_SHARES_ENTRY_POINT_NAME: &str = "mint_shares";
+/// Name of `redeem` entry point.
+pub const REDEEM_ENTRY_POINT_NAME: &str = "redeem";
+/// Name of `total_assets` entry point.
+pub const TOTAL_ASSETS_ENTRY_POINT_NAME: &str = "total_assets";
+/// Name of `convert_to_shares` entry point.
+pub const CONVERT_TO_SHARES_ENTRY_POINT_NAME: &str = "convert_to_shares";
+/// Name of `convert_to_assets` entry point.
+pub const CONVERT_TO_ASSETS_ENTRY_POINT_NAME: &str = "convert_to_assets";
+/// Name of `configure_minter` entry point.
+pub const CONFIGURE_MINTER_ENTRY_POINT_NAME: &str = "configure_minter";
+/// Name of `minter_allowance` entry point.
+pub const MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str = "minter_allowance";
+
+/// Name of `change_mint_window` entry point.
+pub const CHANGE_MINT_WINDOW_ENTRY_POINT_NAME: &str = "change_mint_window";
+
+/// Name of `change_max_balance` entry point.
+pub const CHANGE_MAX_BALANCE_ENTRY_POINT_NAME: &str = "change_max_balance";
+
+/// Name of `set_default_transfer_limit` entry point.
+pub const SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME: &str = "set_default_transfer_limit";
+/// Name of `set_transfer_limit` entry point.
+pub const SET_TRANSFER_LIMIT_ENTRY_POINT_NAME: &str = "set_transfer_limit";
+/// Name of `remaining_daily_limit` entry point.
+pub const REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME: &str = "remaining_daily_limit";
+
+/// Name of `hold` entry point.
+pub const HOLD_ENTRY_POINT_NAME: &str = "hold";
+/// Name of `execute_hold` entry point.
+pub const EXECUTE_HOLD_ENTRY_POINT_NAME: &str = "execute_hold";
+/// Name of `release_hold` entry point.
+pub const RELEASE_HOLD_ENTRY_POINT_NAME: &str = "release_hold";
+/// Name of `balance_on_hold` entry point.
+pub const BALANCE_ON_HOLD_ENTRY_POINT_NAME: &str = "balance_on_hold";
+/// Name of `create_stream` entry point.
+pub const CREATE_STREAM_ENTRY_POINT_NAME: &str = "create_stream";
+/// Name of `withdraw_from_stream` entry point.
+pub const WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME: &str = "withdraw_from_stream";
+/// Name of `cancel_stream` entry point.
+pub const CANCEL_STREAM_ENTRY_POINT_NAME: &str = "cancel_stream";
+/// Name of `stream_balance` entry point.
+pub const STREAM_BALANCE_ENTRY_POINT_NAME: &str = "stream_balance";
+/// Name of `distribute` entry point.
+pub const DISTRIBUTE_ENTRY_POINT_NAME: &str = "distribute";
+/// Name of `withdrawable_dividend_of` entry point.
+pub const WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME: &str = "withdrawable_dividend_of";
+/// Name of `claim_dividend` entry point.
+pub const CLAIM_DIVIDEND_ENTRY_POINT_NAME: &str = "claim_dividend";
+
+/// Name of `bridge_mint` entry point.
+pub const BRIDGE_MINT_ENTRY_POINT_NAME: &str = "bridge_mint";
+/// Name of `bridge_burn` entry point.
+pub const BRIDGE_BURN_ENTRY_POINT_NAME: &str = "bridge_burn";
+
 pub const INCREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "increase_allowance";
 pub const DECREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "decrease_allowance";
 
@@ -58,6 +191,86 @@ pub const SPENDER: &str = "spender";
 pub const AMOUNT: &str = "amount";
 /// Name of `recipient` runtime argument.
 pub const RECIPIENT: &str = "recipient";
+/// Name of `purse` runtime argument.
+pub const PURSE: &str = "purse";
+/// Name of `assets` runtime argument.
+pub const ASSETS: &str = "assets";
+/// Name of `shares` runtime argument.
+pub const SHARES: &str = "shares";
+/// Name of `minter` runtime argument.
+pub const MINTER: &str = "minter";
+/// Name of `allowance` runtime argument.
+pub const ALLOWANCE: &str = "allowance";
+/// Name of `memo` runtime argument.
+pub const MEMO: &str = "memo";
+/// Maximum length of a transfer memo, in bytes.
+pub const MAX_MEMO_LENGTH: usize = 256;
+/// Name of `hold_id` runtime argument.
+pub const HOLD_ID: &str = "hold_id";
+/// Name of `notary` runtime argument.
+pub const NOTARY: &str = "notary";
+/// Name of `expiration` runtime argument.
+pub const EXPIRATION: &str = "expiration";
+/// Name of `stream_id` runtime argument.
+pub const STREAM_ID: &str = "stream_id";
+/// Name of `start_time` runtime argument.
+pub const START_TIME: &str = "start_time";
+/// Name of `end_time` runtime argument.
+pub const END_TIME: &str = "end_time";
+/// Name of `action` runtime argument.
+pub const ACTION: &str = "action";
+/// Name of `args` runtime argument.
+pub const ARGS: &str = "args";
+/// Name of `proposal_id` runtime argument.
+pub const PROPOSAL_ID: &str = "proposal_id";
+/// Name of `operation_id` runtime argument.
+pub const OPERATION_ID: &str = "operation_id";
+/// Name of `root` runtime argument.
+pub const ROOT: &str = "root";
+/// Name of `total` runtime argument.
+pub const TOTAL: &str = "total";
+/// Name of `index` runtime argument.
+pub const INDEX: &str = "index";
+/// Name of `account` runtime argument.
+pub const ACCOUNT: &str = "account";
+/// Name of `proof` runtime argument.
+pub const PROOF: &str = "proof";
+/// Name of `nonce` runtime argument.
+pub const NONCE: &str = "nonce";
+/// Name of `expiry` runtime argument.
+pub const EXPIRY: &str = "expiry";
+/// Name of `signer_public_key` runtime argument.
+pub const SIGNER_PUBLIC_KEY: &str = "signer_public_key";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE: &str = "signature";
+/// Name of `from` runtime argument.
+pub const FROM: &str = "from";
+/// Name of `to` runtime argument.
+pub const TO: &str = "to";
+/// Name of `valid_after` runtime argument.
+pub const VALID_AFTER: &str = "valid_after";
+/// Name of `valid_before` runtime argument.
+pub const VALID_BEFORE: &str = "valid_before";
+/// Name of `addresses` runtime argument.
+pub const ADDRESSES: &str = "addresses";
+/// Name of `pairs` runtime argument.
+pub const PAIRS: &str = "pairs";
+/// Name of `start` runtime argument.
+pub const START: &str = "start";
+/// Name of `limit` runtime argument.
+pub const LIMIT: &str = "limit";
+/// Name of `icon_url` runtime argument.
+pub const ICON_URL: &str = "icon_url";
+/// Name of `website` runtime argument.
+pub const WEBSITE: &str = "website";
+/// Name of `description` runtime argument.
+pub const DESCRIPTION: &str = "description";
+/// Name of `external_ref` runtime argument.
+pub const EXTERNAL_REF: &str = "external_ref";
+/// Name of `destination_chain` runtime argument.
+pub const DESTINATION_CHAIN: &str = "destination_chain";
+/// Name of `destination_address` runtime argument.
+pub const DESTINATION_ADDRESS: &str = "destination_address";
 pub const PACKAGE_HASH: &str = "package_hash";
 pub const EVENTS_MODE: &str = "events_mode";
 pub const SECURITY_BADGES: &str = "security_badges";
@@ -65,5 +278,83 @@ pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const BURNER_LIST: &str = "burner_list";
 pub const NONE_LIST: &str = "none_list";
+pub const REBASER_LIST: &str = "rebaser_list";
+pub const BRIDGE_LIST: &str = "bridge_list";
 pub const MINT_AND_BURN_LIST: &str = "mint_and_burn_list";
 pub const ENABLE_MINT_BURN: &str = "enable_mint_burn";
+pub const ENABLE_TRANSFER_FEE: &str = "enable_transfer_fee";
+pub const TRANSFER_FEE_BPS: &str = "transfer_fee_bps";
+pub const TRANSFER_FEE_TREASURY: &str = "transfer_fee_treasury";
+pub const FEE_EXEMPTIONS: &str = "fee_exemptions";
+pub const EXEMPT_LIST: &str = "exempt_list";
+pub const NON_EXEMPT_LIST: &str = "non_exempt_list";
+
+/// Number of basis points in one whole, i.e. 100%.
+pub const BASIS_POINTS: u32 = 10_000;
+/// Upper bound for the transfer fee rate, in basis points (10%).
+pub const MAX_TRANSFER_FEE_BPS: u32 = 1_000;
+pub const ENABLE_REBASING: &str = "enable_rebasing";
+pub const REBASE_INDEX: &str = "rebase_index";
+/// Name of `new_total_supply` runtime argument.
+pub const NEW_TOTAL_SUPPLY: &str = "new_total_supply";
+
+/// Fixed-point precision of the rebase index; an index of this value means one share is worth one
+/// token.
+pub const REBASE_INDEX_PRECISION: u128 = 1_000_000_000_000_000_000;
+pub const WRAPPER_MODE: &str = "wrapper_mode";
+pub const WRAPPER_PURSE: &str = "wrapper_purse";
+pub const UNDERLYING_TOKEN: &str = "underlying_token";
+pub const LEGACY_TOKEN: &str = "legacy_token";
+pub const LEGACY_RATIO_NUMERATOR: &str = "legacy_ratio_numerator";
+pub const LEGACY_RATIO_DENOMINATOR: &str = "legacy_ratio_denominator";
+pub const LEGACY_MIGRATION_OPEN: &str = "legacy_migration_open";
+pub const COUNCIL: &str = "council";
+pub const COUNCIL_THRESHOLD: &str = "council_threshold";
+pub const PROPOSALS: &str = "proposals";
+pub const PROPOSAL_COUNT: &str = "proposal_count";
+pub const TIMELOCK_DELAY_SECONDS: &str = "timelock_delay_seconds";
+pub const TIMELOCK_SCHEDULER: &str = "timelock_scheduler";
+pub const SCHEDULED_OPERATIONS: &str = "scheduled_operations";
+pub const OPERATION_COUNT: &str = "operation_count";
+pub const MERKLE_ROOT: &str = "merkle_root";
+pub const AIRDROP_REMAINING: &str = "airdrop_remaining";
+pub const AIRDROP_EPOCH: &str = "airdrop_epoch";
+pub const AIRDROP_CLAIMED: &str = "airdrop_claimed";
+pub const REDEEMED_VOUCHERS: &str = "redeemed_vouchers";
+pub const AUTHORIZATION_STATES: &str = "authorization_states";
+/// Name of dictionary-key for the extended token `metadata`
+pub const METADATA: &str = "metadata";
+/// Maximum length of a token metadata field, in bytes.
+pub const MAX_METADATA_FIELD_LENGTH: usize = 1_024;
+/// Maximum number of balances, allowances or holders that can be queried at once.
+pub const MAX_BATCH_LENGTH: usize = 100;
+/// Version of the layout of the value returned by the `token_info` entry point.
+pub const TOKEN_INFO_SCHEMA_VERSION: u32 = 3;
+pub const PROCESSED_REFS: &str = "processed_refs";
+pub const HOLDS: &str = "holds";
+pub const BALANCES_ON_HOLD: &str = "balances_on_hold";
+pub const STREAMS: &str = "streams";
+pub const STREAM_COUNT: &str = "stream_count";
+pub const DIVIDENDS_MODE: &str = "dividends_mode";
+pub const DIVIDEND_TOKEN: &str = "dividend_token";
+pub const DIVIDEND_PURSE: &str = "dividend_purse";
+pub const DIVIDEND_PER_SHARE: &str = "dividend_per_share";
+pub const DIVIDEND_CORRECTIONS: &str = "dividend_corrections";
+pub const ENABLE_MINTER_ALLOWANCES: &str = "enable_minter_allowances";
+pub const MINTER_ALLOWANCES: &str = "minter_allowances";
+pub const MINT_WINDOW_SECONDS: &str = "mint_window_seconds";
+pub const MINT_WINDOW_LIMIT: &str = "mint_window_limit";
+pub const MINT_WINDOW_MINTS: &str = "mint_window_mints";
+/// Maximum number of mints kept to enforce the rolling mint window.
+pub const MAX_MINT_WINDOW_ENTRIES: usize = 32;
+pub const ENABLE_MAX_BALANCE: &str = "enable_max_balance";
+pub const MAX_BALANCE: &str = "max_balance";
+pub const MAX_BALANCE_EXEMPTIONS: &str = "max_balance_exemptions";
+pub const ENABLE_TRANSFER_LIMITS: &str = "enable_transfer_limits";
+pub const DAILY_TRANSFER_LIMIT: &str = "daily_transfer_limit";
+pub const TRANSFER_LIMIT_OVERRIDES: &str = "transfer_limit_overrides";
+pub const DAILY_TRANSFERS: &str = "daily_transfers";
+pub const ENABLE_HOLDER_REGISTRY: &str = "enable_holder_registry";
+pub const HOLDER_COUNT: &str = "holder_count";
+pub const HOLDERS: &str = "holders";
+pub const HOLDER_INDEXES: &str = "holder_indexes";
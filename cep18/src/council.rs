@@ -0,0 +1,256 @@
+//! Implementation of the admin council mode, in which admin actions need the approval of M out of
+//! N council members.
+//!
+//! In this mode the contract's own package holds the Admin badge instead of the installing
+//! account. Council members propose an action as the name of an entry point of this contract and
+//! its serialized runtime arguments, and approve proposals until the threshold is met. Executing a
+//! proposal then calls the entry point from the contract itself, so the usual security checks
+//! apply unchanged. Approvals only count while the approver is a member of the council, and
+//! changes to the council and its threshold can only be made through a proposal.
+//!
+//! Only the admin entry points in [`ADMIN_ACTIONS`] can be proposed. Since the contract's own
+//! package key also holds the stream and airdrop escrow, allowing any entry point would let a
+//! quorum move escrowed tokens with e.g. `transfer`.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{self, dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTyped, ContractPackageHash, Key, RuntimeArgs,
+};
+
+use crate::{
+    constants::{
+        CANCEL_SCHEDULED_ENTRY_POINT_NAME, CHANGE_COUNCIL_ENTRY_POINT_NAME,
+        CHANGE_MAX_BALANCE_ENTRY_POINT_NAME, CHANGE_MINT_WINDOW_ENTRY_POINT_NAME,
+        CHANGE_SECURITY_ENTRY_POINT_NAME, CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME,
+        CLOSE_LEGACY_MIGRATION_ENTRY_POINT_NAME, CONFIGURE_MINTER_ENTRY_POINT_NAME, COUNCIL,
+        COUNCIL_THRESHOLD, MINT_ENTRY_POINT_NAME, PROPOSALS, PROPOSAL_COUNT,
+        REBASE_ENTRY_POINT_NAME, SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME,
+        SET_MERKLE_ROOT_ENTRY_POINT_NAME, SET_METADATA_ENTRY_POINT_NAME,
+        SET_TRANSFER_LIMIT_ENTRY_POINT_NAME,
+    },
+    error::Cep18Error,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Entry points that can be proposed to the council.
+pub(crate) const ADMIN_ACTIONS: [&str; 14] = [
+    MINT_ENTRY_POINT_NAME,
+    CHANGE_SECURITY_ENTRY_POINT_NAME,
+    CHANGE_COUNCIL_ENTRY_POINT_NAME,
+    CONFIGURE_MINTER_ENTRY_POINT_NAME,
+    CHANGE_MINT_WINDOW_ENTRY_POINT_NAME,
+    CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME,
+    CHANGE_MAX_BALANCE_ENTRY_POINT_NAME,
+    SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME,
+    SET_TRANSFER_LIMIT_ENTRY_POINT_NAME,
+    SET_METADATA_ENTRY_POINT_NAME,
+    SET_MERKLE_ROOT_ENTRY_POINT_NAME,
+    CLOSE_LEGACY_MIGRATION_ENTRY_POINT_NAME,
+    CANCEL_SCHEDULED_ENTRY_POINT_NAME,
+    REBASE_ENTRY_POINT_NAME,
+];
+
+fn check_action(action: &str) -> Result<(), Cep18Error> {
+    if !ADMIN_ACTIONS.contains(&action) {
+        return Err(Cep18Error::InvalidProposal);
+    }
+    Ok(())
+}
+
+/// A proposal to call the entry point `action` of this contract with the serialized runtime
+/// arguments `args`.
+#[derive(Clone)]
+pub(crate) struct Proposal {
+    pub proposer: Key,
+    pub action: String,
+    pub args: Bytes,
+    pub approvals: Vec<Key>,
+    pub executed: bool,
+}
+
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.proposer.to_bytes()?);
+        result.append(&mut self.action.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.proposer.serialized_length()
+            + self.action.serialized_length()
+            + self.args.serialized_length()
+            + self.approvals.serialized_length()
+            + self.executed.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (proposer, remainder) = Key::from_bytes(bytes)?;
+        let (action, remainder) = String::from_bytes(remainder)?;
+        let (args, remainder) = Bytes::from_bytes(remainder)?;
+        let (approvals, remainder) = Vec::<Key>::from_bytes(remainder)?;
+        let (executed, remainder) = bool::from_bytes(remainder)?;
+        let proposal = Proposal {
+            proposer,
+            action,
+            args,
+            approvals,
+            executed,
+        };
+        Ok((proposal, remainder))
+    }
+}
+
+/// Returns `true` if the admin council mode is enabled for this contract instance.
+pub(crate) fn is_council_enabled() -> bool {
+    runtime::get_key(COUNCIL).is_some()
+}
+
+fn read_council() -> Result<Vec<Key>, Cep18Error> {
+    read_optional_from(COUNCIL).ok_or(Cep18Error::CouncilDisabled)
+}
+
+/// Checks that `council` has no duplicate members and that `threshold` is between one and its
+/// size.
+pub(crate) fn validate_council(council: &[Key], threshold: u32) -> Result<(), Cep18Error> {
+    if council
+        .iter()
+        .enumerate()
+        .any(|(index, member)| council[..index].contains(member))
+    {
+        return Err(Cep18Error::InvalidCouncil);
+    }
+    if threshold == 0 || threshold as usize > council.len() {
+        return Err(Cep18Error::InvalidCouncilThreshold);
+    }
+    Ok(())
+}
+
+/// Replaces the council and its threshold.
+pub(crate) fn write_council(council: Vec<Key>, threshold: u32) -> Result<(), Cep18Error> {
+    if !is_council_enabled() {
+        return Err(Cep18Error::CouncilDisabled);
+    }
+    validate_council(&council, threshold)?;
+    storage::write(get_uref(COUNCIL), council);
+    storage::write(get_uref(COUNCIL_THRESHOLD), threshold);
+    Ok(())
+}
+
+/// Checks that `address` is a member of the council.
+fn check_member(address: Key) -> Result<Vec<Key>, Cep18Error> {
+    let council = read_council()?;
+    if !council.contains(&address) {
+        return Err(Cep18Error::NotCouncilMember);
+    }
+    Ok(council)
+}
+
+fn read_proposal(proposal_id: u64) -> Result<Proposal, Cep18Error> {
+    dictionary_get(get_uref(PROPOSALS), &proposal_id.to_string())
+        .unwrap_or_revert()
+        .ok_or(Cep18Error::ProposalNotFound)
+}
+
+fn write_proposal(proposal_id: u64, proposal: Proposal) {
+    dictionary_put(get_uref(PROPOSALS), &proposal_id.to_string(), proposal)
+}
+
+/// Stores a proposal by `proposer` to call `action`, one of the [`ADMIN_ACTIONS`], with `args`,
+/// approved by the proposer, and returns its id.
+pub(crate) fn create_proposal(
+    proposer: Key,
+    action: String,
+    args: Bytes,
+) -> Result<u64, Cep18Error> {
+    check_member(proposer)?;
+    check_action(&action)?;
+    bytesrepr::deserialize::<RuntimeArgs>(args.to_vec())
+        .map_err(|_| Cep18Error::InvalidProposal)?;
+
+    let proposal_id: u64 = read_from(PROPOSAL_COUNT);
+    write_proposal(
+        proposal_id,
+        Proposal {
+            proposer,
+            action,
+            args,
+            approvals: alloc::vec![proposer],
+            executed: false,
+        },
+    );
+    storage::write(get_uref(PROPOSAL_COUNT), proposal_id + 1);
+    Ok(proposal_id)
+}
+
+/// Records the approval of the proposal `proposal_id` by `approver`.
+pub(crate) fn approve_proposal(proposal_id: u64, approver: Key) -> Result<(), Cep18Error> {
+    check_member(approver)?;
+    let mut proposal = read_proposal(proposal_id)?;
+    if proposal.executed {
+        return Err(Cep18Error::ProposalAlreadyExecuted);
+    }
+    if proposal.approvals.contains(&approver) {
+        return Err(Cep18Error::ProposalAlreadyApproved);
+    }
+    proposal.approvals.push(approver);
+    write_proposal(proposal_id, proposal);
+    Ok(())
+}
+
+/// Marks the proposal `proposal_id` as executed by `executor` once enough current council members
+/// have approved it, and returns it for execution.
+fn take_approved_proposal(proposal_id: u64, executor: Key) -> Result<Proposal, Cep18Error> {
+    let council = check_member(executor)?;
+    let mut proposal = read_proposal(proposal_id)?;
+    if proposal.executed {
+        return Err(Cep18Error::ProposalAlreadyExecuted);
+    }
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| council.contains(approver))
+        .count();
+    if approvals < read_from::<u32>(COUNCIL_THRESHOLD) as usize {
+        return Err(Cep18Error::ProposalThresholdNotMet);
+    }
+    proposal.executed = true;
+    write_proposal(proposal_id, proposal.clone());
+    Ok(proposal)
+}
+
+/// Executes the proposal `proposal_id` by calling its action from the contract itself.
+pub(crate) fn execute_proposal(proposal_id: u64, executor: Key) -> Result<(), Cep18Error> {
+    let proposal = take_approved_proposal(proposal_id, executor)?;
+    check_action(&proposal.action)?;
+    let args: RuntimeArgs =
+        bytesrepr::deserialize(proposal.args.to_vec()).map_err(|_| Cep18Error::InvalidProposal)?;
+    let package_hash = utils::get_self_key()
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(Cep18Error::InvalidContext)?;
+    runtime::call_versioned_contract::<()>(package_hash, None, &proposal.action, args);
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Implementation of the dividends modes, in which revenue is distributed to token holders pro
+//! rata to their balances.
+//!
+//! Every distribution increases a global dividend per share, scaled by 2^128. The dividend
+//! of a holder is its balance times the increase of the dividend per share while it held that
+//! balance. Whenever a balance changes, the dividend earned with the old balance is credited to the
+//! holder and its correction term is reset to the new balance times the current dividend per
+//! share, so claiming never requires iterating over all holders. Balances and the total supply are
+//! read in stored units, so that rebases do not affect dividends.
+use core::convert::TryFrom;
+
+use casper_contract::{
+    contract_api::storage::{self, dictionary_get, dictionary_put},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef, U256};
+
+use crate::{
+    balances::{get_balances_uref, read_balance_from},
+    constants::{DIVIDENDS_MODE, DIVIDEND_CORRECTIONS, DIVIDEND_PER_SHARE, DIVIDEND_PURSE},
+    error::Cep18Error,
+    modalities::DividendsMode,
+    utils::{self, get_total_supply_uref, get_uref, read_from, read_optional_from},
+};
+
+/// Scale of the dividend per share, so that small distributions over a large supply are not
+/// rounded away.
+fn magnitude() -> U256 {
+    U256::one() << 128
+}
+
+/// Returns the dividends mode of this contract instance.
+pub(crate) fn read_dividends_mode() -> DividendsMode {
+    DividendsMode::try_from(read_optional_from::<u8>(DIVIDENDS_MODE).unwrap_or_default())
+        .unwrap_or_revert()
+}
+
+/// Getter for the purse holding the motes distributed in the `Cspr` dividends mode.
+pub(crate) fn get_dividend_purse() -> URef {
+    get_uref(DIVIDEND_PURSE)
+}
+
+/// Reads the correction term and the credited dividend of `address`.
+fn read_correction(address: Key) -> (U256, U256) {
+    dictionary_get(
+        get_uref(DIVIDEND_CORRECTIONS),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn write_correction(address: Key, correction: U256, credited: U256) {
+    dictionary_put(
+        get_uref(DIVIDEND_CORRECTIONS),
+        &utils::make_dictionary_item_key(address),
+        (correction, credited),
+    )
+}
+
+/// Returns the magnified dividend accumulated by `balance` since the first distribution.
+fn accumulated(balance: U256) -> Result<U256, Cep18Error> {
+    balance
+        .checked_mul(read_from::<U256>(DIVIDEND_PER_SHARE))
+        .ok_or(Cep18Error::Overflow)
+}
+
+/// Credits the dividend earned by `address` with `balance` and resets its correction term to
+/// `new_balance`. Must be called before every change of a balance, given in stored units.
+pub(crate) fn update_correction(
+    address: Key,
+    balance: U256,
+    new_balance: U256,
+) -> Result<(), Cep18Error> {
+    if read_dividends_mode() == DividendsMode::Disabled || balance == new_balance {
+        return Ok(());
+    }
+    let credited = magnified_dividend_of(address, balance)?;
+    write_correction(address, accumulated(new_balance)?, credited);
+    Ok(())
+}
+
+/// Spreads `amount` over all holders except the contract itself, whose escrowed tokens do not earn
+/// dividends.
+pub(crate) fn distribute(amount: U256) -> Result<(), Cep18Error> {
+    let total_supply: U256 = utils::read_total_supply_from(get_total_supply_uref());
+    let escrowed = read_balance_from(get_balances_uref(), utils::get_self_key());
+    let eligible_supply = total_supply.saturating_sub(escrowed);
+    if amount.is_zero() || eligible_supply.is_zero() {
+        return Err(Cep18Error::InvalidDividendAmount);
+    }
+    let increase = amount
+        .checked_mul(magnitude())
+        .ok_or(Cep18Error::Overflow)?
+        / eligible_supply;
+    let dividend_per_share = read_from::<U256>(DIVIDEND_PER_SHARE)
+        .checked_add(increase)
+        .ok_or(Cep18Error::Overflow)?;
+    storage::write(get_uref(DIVIDEND_PER_SHARE), dividend_per_share);
+    Ok(())
+}
+
+/// Returns the magnified dividend of `address` with the given balance, including the remainder
+/// below one unit.
+fn magnified_dividend_of(address: Key, balance: U256) -> Result<U256, Cep18Error> {
+    let (correction, credited) = read_correction(address);
+    credited
+        .checked_add(accumulated(balance)?.saturating_sub(correction))
+        .ok_or(Cep18Error::Overflow)
+}
+
+/// Returns the dividend `address` can claim.
+pub(crate) fn withdrawable_dividend_of(address: Key) -> Result<U256, Cep18Error> {
+    let balance = read_balance_from(get_balances_uref(), address);
+    Ok(magnified_dividend_of(address, balance)? / magnitude())
+}
+
+/// Marks the dividend of `address` as claimed and returns it. The remainder below one unit stays
+/// credited to the holder.
+pub(crate) fn claim_dividend(address: Key) -> Result<U256, Cep18Error> {
+    let balance = read_balance_from(get_balances_uref(), address);
+    let magnified = magnified_dividend_of(address, balance)?;
+    let dividend = magnified / magnitude();
+    if dividend.is_zero() {
+        return Err(Cep18Error::NoDividendToClaim);
+    }
+    write_correction(address, accumulated(balance)?, magnified % magnitude());
+    Ok(dividend)
+}
@@ -1,18 +1,51 @@
 //! Contains definition of the entry points.
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
-    U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Key, Parameter, PublicKey, U256,
 };
 
-use crate::constants::{
-    ADDRESS, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
-    BALANCE_OF_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
-    DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME,
-    INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INIT_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME,
-    NAME_ENTRY_POINT_NAME, OWNER, RECIPIENT, SPENDER, SYMBOL_ENTRY_POINT_NAME,
-    TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+use crate::{
+    constants::{
+        ACCOUNT, ACTION, ADDRESS, ADDRESSES, ALLOWANCE, ALLOWANCES_OF_ENTRY_POINT_NAME,
+        ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
+        APPROVE_PROPOSAL_ENTRY_POINT_NAME, ARGS, ASSETS, AUDIT_SUPPLY_ENTRY_POINT_NAME,
+        BALANCES_OF_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME,
+        BALANCE_ON_HOLD_ENTRY_POINT_NAME, BRIDGE_BURN_ENTRY_POINT_NAME,
+        BRIDGE_MINT_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CANCEL_AUTHORIZATION_ENTRY_POINT_NAME,
+        CANCEL_SCHEDULED_ENTRY_POINT_NAME, CANCEL_STREAM_ENTRY_POINT_NAME,
+        CHANGE_COUNCIL_ENTRY_POINT_NAME, CHANGE_MAX_BALANCE_ENTRY_POINT_NAME,
+        CHANGE_MINT_WINDOW_ENTRY_POINT_NAME, CHANGE_SECURITY_ENTRY_POINT_NAME,
+        CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME, CLAIM_DIVIDEND_ENTRY_POINT_NAME,
+        CLAIM_ENTRY_POINT_NAME, CLOSE_LEGACY_MIGRATION_ENTRY_POINT_NAME,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME, CONVERT_TO_ASSETS_ENTRY_POINT_NAME,
+        CONVERT_TO_SHARES_ENTRY_POINT_NAME, COUNCIL, COUNCIL_THRESHOLD,
+        CREATE_PROPOSAL_ENTRY_POINT_NAME, CREATE_STREAM_ENTRY_POINT_NAME, DAILY_TRANSFER_LIMIT,
+        DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DEPOSIT_ENTRY_POINT_NAME,
+        DESTINATION_ADDRESS, DESTINATION_CHAIN, DISTRIBUTE_ENTRY_POINT_NAME, END_TIME,
+        EXECUTE_HOLD_ENTRY_POINT_NAME, EXECUTE_PROPOSAL_ENTRY_POINT_NAME,
+        EXECUTE_SCHEDULED_ENTRY_POINT_NAME, EXPIRATION, EXPIRY, EXTERNAL_REF, FROM,
+        HOLDERS_ENTRY_POINT_NAME, HOLDER_COUNT_ENTRY_POINT_NAME, HOLD_ENTRY_POINT_NAME, HOLD_ID,
+        INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INDEX, INIT_ENTRY_POINT_NAME, LIMIT, MEMO,
+        MIGRATE_FROM_LEGACY_ENTRY_POINT_NAME, MINTER, MINTER_ALLOWANCE_ENTRY_POINT_NAME,
+        MINT_ENTRY_POINT_NAME, MINT_SHARES_ENTRY_POINT_NAME, MINT_WITH_VOUCHER_ENTRY_POINT_NAME,
+        NAME_ENTRY_POINT_NAME, NEW_TOTAL_SUPPLY, NONCE, NOTARY, OPERATION_ID, OWNER, PAIRS, PROOF,
+        PROPOSAL_ID, REBASE_ENTRY_POINT_NAME, RECIPIENT, REDEEM_ENTRY_POINT_NAME,
+        RELEASE_HOLD_ENTRY_POINT_NAME, REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME, ROOT,
+        SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME, SET_MERKLE_ROOT_ENTRY_POINT_NAME,
+        SET_METADATA_ENTRY_POINT_NAME, SET_TRANSFER_LIMIT_ENTRY_POINT_NAME, SHARES, SIGNATURE,
+        SIGNER_PUBLIC_KEY, SPENDER, START, START_TIME, STREAM_BALANCE_ENTRY_POINT_NAME, STREAM_ID,
+        SYMBOL_ENTRY_POINT_NAME, TO, TOKEN_INFO_ENTRY_POINT_NAME, TOKEN_METADATA_ENTRY_POINT_NAME,
+        TOTAL, TOTAL_ASSETS_ENTRY_POINT_NAME, TOTAL_BURNED_ENTRY_POINT_NAME,
+        TOTAL_MINTED_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME, TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME,
+        TRANSFER_WITH_AUTHORIZATION_ENTRY_POINT_NAME, TRANSFER_WITH_MEMO_ENTRY_POINT_NAME,
+        UNWRAP_ENTRY_POINT_NAME, VALID_AFTER, VALID_BEFORE,
+        WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME, WITHDRAW_ENTRY_POINT_NAME,
+        WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME, WRAP_ENTRY_POINT_NAME,
+    },
+    token_info::TokenInfo,
 };
 
 /// Returns the `name` entry point.
@@ -52,6 +85,73 @@ pub fn transfer_from() -> EntryPoint {
     )
 }
 
+/// Returns the `transfer_with_memo` entry point.
+pub fn transfer_with_memo() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_WITH_MEMO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(MEMO, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_from_with_memo` entry point.
+pub fn transfer_from_with_memo() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(MEMO, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_with_authorization` entry point.
+pub fn transfer_with_authorization() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_WITH_AUTHORIZATION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(VALID_AFTER, u64::cl_type()),
+            Parameter::new(VALID_BEFORE, u64::cl_type()),
+            Parameter::new(NONCE, <[u8; 32]>::cl_type()),
+            Parameter::new(SIGNER_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_authorization` entry point.
+pub fn cancel_authorization() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_AUTHORIZATION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(NONCE, <[u8; 32]>::cl_type()),
+            Parameter::new(SIGNER_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `allowance` entry point.
 pub fn allowance() -> EntryPoint {
     EntryPoint::new(
@@ -66,6 +166,53 @@ pub fn allowance() -> EntryPoint {
     )
 }
 
+/// Returns the `balances_of` entry point.
+pub fn balances_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESSES, Vec::<Key>::cl_type())],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `allowances_of` entry point.
+pub fn allowances_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ALLOWANCES_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(PAIRS, Vec::<(Key, Key)>::cl_type())],
+        Vec::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_count` entry point.
+pub fn holder_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holders` entry point.
+pub fn holders() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(START, u64::cl_type()),
+            Parameter::new(LIMIT, u64::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `approve` entry point.
 pub fn approve() -> EntryPoint {
     EntryPoint::new(
@@ -144,6 +291,39 @@ pub fn total_supply() -> EntryPoint {
     )
 }
 
+/// Returns the `total_minted` entry point.
+pub fn total_minted() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_MINTED_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_burned` entry point.
+pub fn total_burned() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_BURNED_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `audit_supply` entry point.
+pub fn audit_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(AUDIT_SUPPLY_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `decimals` entry point.
 pub fn decimals() -> EntryPoint {
     EntryPoint::new(
@@ -155,6 +335,48 @@ pub fn decimals() -> EntryPoint {
     )
 }
 
+/// Returns the `token_metadata` entry point.
+pub fn token_metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOKEN_METADATA_ENTRY_POINT_NAME),
+        Vec::new(),
+        BTreeMap::<String, String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `token_info` entry point.
+pub fn token_info() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOKEN_INFO_ENTRY_POINT_NAME),
+        Vec::new(),
+        TokenInfo::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_metadata` entry point.
+pub fn set_metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_METADATA_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "name" : String
+            - "symbol" : String
+            - "icon_url" : String
+            - "website" : String
+            - "description" : String
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `burn` entry point.
 pub fn burn() -> EntryPoint {
     EntryPoint::new(
@@ -195,6 +417,377 @@ pub fn change_security() -> EntryPoint {
             - "minter_list" : Vec<Key>
             - "burner_list" : Vec<Key>
             - "none_list" : Vec<Key>
+            - "rebaser_list" : Vec<Key>
+            - "bridge_list" : Vec<Key>
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `change_transfer_fee` entry point.
+pub fn change_transfer_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "transfer_fee_bps" : u32
+            - "transfer_fee_treasury" : Key
+            - "exempt_list" : Vec<Key>
+            - "non_exempt_list" : Vec<Key>
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `rebase` entry point.
+pub fn rebase() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REBASE_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW_TOTAL_SUPPLY, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `deposit` entry point.
+pub fn deposit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DEPOSIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "purse" : URef (required in the `Cspr` wrapper mode)
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw` entry point.
+pub fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "purse" : URef
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint_shares` entry point.
+pub fn mint_shares() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_SHARES_ENTRY_POINT_NAME),
+        vec![Parameter::new(SHARES, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `wrap` entry point.
+pub fn wrap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WRAP_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unwrap` entry point.
+pub fn unwrap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNWRAP_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `migrate_from_legacy` entry point.
+pub fn migrate_from_legacy() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MIGRATE_FROM_LEGACY_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `close_legacy_migration` entry point.
+pub fn close_legacy_migration() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLOSE_LEGACY_MIGRATION_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_proposal` entry point.
+pub fn create_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_PROPOSAL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACTION, String::cl_type()),
+            Parameter::new(ARGS, Bytes::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_proposal` entry point.
+pub fn approve_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_proposal` entry point.
+pub fn execute_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `change_council` entry point.
+pub fn change_council() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_COUNCIL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(COUNCIL, Vec::<Key>::cl_type()),
+            Parameter::new(COUNCIL_THRESHOLD, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_scheduled` entry point.
+pub fn execute_scheduled() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_SCHEDULED_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_scheduled` entry point.
+pub fn cancel_scheduled() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_SCHEDULED_ENTRY_POINT_NAME),
+        vec![Parameter::new(OPERATION_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_merkle_root` entry point.
+pub fn set_merkle_root() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MERKLE_ROOT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROOT, <[u8; 32]>::cl_type()),
+            Parameter::new(TOTAL, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `claim` entry point.
+pub fn claim() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAIM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(INDEX, u64::cl_type()),
+            Parameter::new(ACCOUNT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(PROOF, Vec::<[u8; 32]>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint_with_voucher` entry point.
+pub fn mint_with_voucher() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_WITH_VOUCHER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(NONCE, u64::cl_type()),
+            Parameter::new(EXPIRY, u64::cl_type()),
+            Parameter::new(SIGNER_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `redeem` entry point.
+pub fn redeem() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REDEEM_ENTRY_POINT_NAME),
+        vec![Parameter::new(SHARES, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_assets` entry point.
+pub fn total_assets() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_ASSETS_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `convert_to_shares` entry point.
+pub fn convert_to_shares() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONVERT_TO_SHARES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ASSETS, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `convert_to_assets` entry point.
+pub fn convert_to_assets() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONVERT_TO_ASSETS_ENTRY_POINT_NAME),
+        vec![Parameter::new(SHARES, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `distribute` entry point.
+pub fn distribute() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DISTRIBUTE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "purse" : URef
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdrawable_dividend_of` entry point.
+pub fn withdrawable_dividend_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAWABLE_DIVIDEND_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `claim_dividend` entry point.
+pub fn claim_dividend() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAIM_DIVIDEND_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "purse" : URef
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `configure_minter` entry point.
+pub fn configure_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONFIGURE_MINTER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(MINTER, Key::cl_type()),
+            Parameter::new(ALLOWANCE, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `minter_allowance` entry point.
+pub fn minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `change_mint_window` entry point.
+pub fn change_mint_window() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_MINT_WINDOW_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "mint_window_seconds" : u64
+            - "mint_window_limit" : U256
             */
         ],
         CLType::Unit,
@@ -203,6 +796,189 @@ pub fn change_security() -> EntryPoint {
     )
 }
 
+/// Returns the `change_max_balance` entry point.
+pub fn change_max_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_MAX_BALANCE_ENTRY_POINT_NAME),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "max_balance" : U256
+            - "exempt_list" : Vec<Key>
+            - "non_exempt_list" : Vec<Key>
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_default_transfer_limit` entry point.
+pub fn set_default_transfer_limit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME),
+        vec![Parameter::new(DAILY_TRANSFER_LIMIT, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_transfer_limit` entry point.
+pub fn set_transfer_limit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_TRANSFER_LIMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS, Key::cl_type()),
+            Parameter::new(DAILY_TRANSFER_LIMIT, Option::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remaining_daily_limit` entry point.
+pub fn remaining_daily_limit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMAINING_DAILY_LIMIT_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `hold` entry point.
+pub fn hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLD_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(HOLD_ID, String::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(NOTARY, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(EXPIRATION, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_hold` entry point.
+pub fn execute_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(HOLD_ID, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `release_hold` entry point.
+pub fn release_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASE_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(HOLD_ID, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_on_hold` entry point.
+pub fn balance_on_hold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_ON_HOLD_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_stream` entry point.
+pub fn create_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_STREAM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(START_TIME, u64::cl_type()),
+            Parameter::new(END_TIME, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw_from_stream` entry point.
+pub fn withdraw_from_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_stream` entry point.
+pub fn cancel_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `stream_balance` entry point.
+pub fn stream_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STREAM_BALANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_mint` entry point.
+pub fn bridge_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(EXTERNAL_REF, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_burn` entry point.
+pub fn bridge_burn() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_BURN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DESTINATION_CHAIN, String::cl_type()),
+            Parameter::new(DESTINATION_ADDRESS, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `init` entry point.
 pub fn init() -> EntryPoint {
     EntryPoint::new(
@@ -221,16 +997,72 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(name());
     entry_points.add_entry_point(symbol());
     entry_points.add_entry_point(decimals());
+    entry_points.add_entry_point(token_metadata());
+    entry_points.add_entry_point(token_info());
+    entry_points.add_entry_point(set_metadata());
     entry_points.add_entry_point(total_supply());
+    entry_points.add_entry_point(total_minted());
+    entry_points.add_entry_point(total_burned());
+    entry_points.add_entry_point(audit_supply());
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(transfer());
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
+    entry_points.add_entry_point(balances_of());
+    entry_points.add_entry_point(allowances_of());
+    entry_points.add_entry_point(holder_count());
+    entry_points.add_entry_point(holders());
     entry_points.add_entry_point(decrease_allowance());
     entry_points.add_entry_point(increase_allowance());
     entry_points.add_entry_point(transfer_from());
+    entry_points.add_entry_point(transfer_with_memo());
+    entry_points.add_entry_point(transfer_from_with_memo());
+    entry_points.add_entry_point(transfer_with_authorization());
+    entry_points.add_entry_point(cancel_authorization());
     entry_points.add_entry_point(change_security());
     entry_points.add_entry_point(burn());
     entry_points.add_entry_point(mint());
+    entry_points.add_entry_point(change_transfer_fee());
+    entry_points.add_entry_point(rebase());
+    entry_points.add_entry_point(deposit());
+    entry_points.add_entry_point(withdraw());
+    entry_points.add_entry_point(wrap());
+    entry_points.add_entry_point(unwrap());
+    entry_points.add_entry_point(migrate_from_legacy());
+    entry_points.add_entry_point(close_legacy_migration());
+    entry_points.add_entry_point(create_proposal());
+    entry_points.add_entry_point(approve_proposal());
+    entry_points.add_entry_point(execute_proposal());
+    entry_points.add_entry_point(change_council());
+    entry_points.add_entry_point(execute_scheduled());
+    entry_points.add_entry_point(cancel_scheduled());
+    entry_points.add_entry_point(set_merkle_root());
+    entry_points.add_entry_point(claim());
+    entry_points.add_entry_point(mint_with_voucher());
+    entry_points.add_entry_point(mint_shares());
+    entry_points.add_entry_point(redeem());
+    entry_points.add_entry_point(total_assets());
+    entry_points.add_entry_point(convert_to_shares());
+    entry_points.add_entry_point(convert_to_assets());
+    entry_points.add_entry_point(distribute());
+    entry_points.add_entry_point(withdrawable_dividend_of());
+    entry_points.add_entry_point(claim_dividend());
+    entry_points.add_entry_point(configure_minter());
+    entry_points.add_entry_point(minter_allowance());
+    entry_points.add_entry_point(change_mint_window());
+    entry_points.add_entry_point(change_max_balance());
+    entry_points.add_entry_point(set_default_transfer_limit());
+    entry_points.add_entry_point(set_transfer_limit());
+    entry_points.add_entry_point(remaining_daily_limit());
+    entry_points.add_entry_point(hold());
+    entry_points.add_entry_point(execute_hold());
+    entry_points.add_entry_point(release_hold());
+    entry_points.add_entry_point(balance_on_hold());
+    entry_points.add_entry_point(create_stream());
+    entry_points.add_entry_point(withdraw_from_stream());
+    entry_points.add_entry_point(cancel_stream());
+    entry_points.add_entry_point(stream_balance());
+    entry_points.add_entry_point(bridge_mint());
+    entry_points.add_entry_point(bridge_burn());
     entry_points
 }
@@ -48,6 +48,181 @@ pub enum Cep18Error {
     CannotTargetSelfUser = 60017,
     InvalidBurnTarget = 60018,
     MissingPackageHashForUpgrade = 60019,
+    /// The flag to enable the transfer fee mode is invalid.
+    InvalidTransferFeeFlag = 60020,
+    /// The transfer fee rate is invalid or exceeds the allowed maximum.
+    InvalidTransferFee = 60021,
+    /// The transfer fee treasury is missing or invalid.
+    InvalidFeeTreasury = 60022,
+    /// The transfer fee mode is disabled.
+    TransferFeeDisabled = 60023,
+    /// The list of accounts to exempt is invalid.
+    InvalidExemptList = 60024,
+    /// The list of accounts to remove an exemption from is invalid.
+    InvalidNonExemptList = 60025,
+    /// The flag to enable the rebasing mode is invalid.
+    InvalidRebasingFlag = 60026,
+    /// The rebasing mode is disabled.
+    RebasingDisabled = 60027,
+    /// The requested rebase would result in an invalid index.
+    InvalidRebase = 60028,
+    /// The list of accounts that can rebase the token is invalid.
+    InvalidRebaserList = 60029,
+    /// An invalid wrapper mode was specified.
+    InvalidWrapperMode = 60030,
+    /// The operation is not supported by the wrapper mode of this contract.
+    WrapperDisabled = 60031,
+    /// A purse is required to receive the withdrawn motes.
+    MissingPurse = 60032,
+    /// The external reference has already been used to mint tokens.
+    ExternalRefAlreadyProcessed = 60033,
+    /// The list of bridge accounts is invalid.
+    InvalidBridgeList = 60034,
+    /// The flag to enable per-minter mint allowances is invalid.
+    InvalidMinterAllowancesFlag = 60035,
+    /// Per-minter mint allowances are disabled.
+    MinterAllowancesDisabled = 60036,
+    /// The minter does not have enough mint allowance left.
+    MinterAllowanceExceeded = 60037,
+    /// The mint window length or limit is invalid.
+    InvalidMintWindow = 60038,
+    /// The mint would exceed the number of tokens that can be minted in the current window.
+    MintWindowLimitExceeded = 60039,
+    /// The flag to enable the transfer limits mode is invalid.
+    InvalidTransferLimitsFlag = 60040,
+    /// The transfer limits mode is disabled.
+    TransferLimitsDisabled = 60041,
+    /// The transfer would exceed the daily transfer limit of the sender.
+    DailyTransferLimitExceeded = 60042,
+    /// The daily transfer limit is invalid.
+    InvalidDailyTransferLimit = 60043,
+    /// The flag to enable the maximum balance mode is invalid.
+    InvalidMaxBalanceFlag = 60044,
+    /// The maximum balance mode is disabled.
+    MaxBalanceDisabled = 60045,
+    /// The maximum balance is missing or invalid.
+    InvalidMaxBalance = 60046,
+    /// The operation would leave the recipient with more than the maximum balance.
+    MaxBalanceExceeded = 60047,
+    /// A hold with the given id already exists.
+    HoldAlreadyExists = 60048,
+    /// There is no hold with the given id.
+    HoldNotFound = 60049,
+    /// The hold has already been executed or released.
+    HoldNotActive = 60050,
+    /// The hold has expired and can no longer be executed.
+    HoldExpired = 60051,
+    /// The hold can only be released by its payer once it has expired.
+    HoldNotExpired = 60052,
+    /// The memo is missing, invalid or longer than the maximum memo length.
+    InvalidMemo = 60053,
+    /// There is no stream with the given id.
+    StreamNotFound = 60054,
+    /// The stream has no tokens, ends before it starts or streams to its own sender.
+    InvalidStream = 60055,
+    /// The stream has been cancelled.
+    StreamNotActive = 60056,
+    /// The dividends mode is invalid.
+    InvalidDividendsMode = 60057,
+    /// The dividends mode is disabled.
+    DividendsDisabled = 60058,
+    /// The dividend token is missing or invalid.
+    InvalidDividendToken = 60059,
+    /// The distributed amount is zero or there are no holders to distribute it to.
+    InvalidDividendAmount = 60060,
+    /// The caller has no dividend to claim.
+    NoDividendToClaim = 60061,
+    /// The underlying token of the vault is missing or invalid.
+    InvalidUnderlyingToken = 60062,
+    /// The vault operation would move zero assets or shares.
+    InvalidVaultAmount = 60063,
+    /// The wrapped supply would exceed the underlying tokens held by the contract.
+    WrapperInvariantViolated = 60064,
+    /// The legacy token is invalid.
+    InvalidLegacyToken = 60065,
+    /// No legacy token was configured at installation.
+    LegacyMigrationDisabled = 60066,
+    /// The migration from the legacy token has been closed.
+    LegacyMigrationClosed = 60067,
+    /// The legacy conversion ratio has a zero numerator or denominator.
+    InvalidLegacyRatio = 60068,
+    /// The migrated amount converts to zero tokens.
+    InvalidLegacyAmount = 60069,
+    /// The council is invalid or has duplicate members.
+    InvalidCouncil = 60070,
+    /// The council threshold is zero or exceeds the size of the council.
+    InvalidCouncilThreshold = 60071,
+    /// The admin council mode is disabled.
+    CouncilDisabled = 60072,
+    /// The caller is not a member of the council.
+    NotCouncilMember = 60073,
+    /// There is no proposal with the given id.
+    ProposalNotFound = 60074,
+    /// The caller has already approved the proposal.
+    ProposalAlreadyApproved = 60075,
+    /// The proposal has already been executed.
+    ProposalAlreadyExecuted = 60076,
+    /// The proposal does not have enough approvals from current council members.
+    ProposalThresholdNotMet = 60077,
+    /// The proposed action is not an admin entry point or its runtime arguments cannot be
+    /// deserialized.
+    InvalidProposal = 60078,
+    /// The timelock delay is invalid.
+    InvalidTimelockDelay = 60079,
+    /// The timelock is disabled.
+    TimelockDisabled = 60080,
+    /// There is no scheduled operation with the given id.
+    OperationNotFound = 60081,
+    /// The scheduled operation has already been executed or cancelled.
+    OperationNotPending = 60082,
+    /// The delay of the scheduled operation has not passed yet.
+    OperationNotReady = 60083,
+    /// No Merkle root has been set.
+    AirdropNotActive = 60084,
+    /// The Merkle proof does not match the current root.
+    InvalidMerkleProof = 60085,
+    /// The airdrop has already been claimed for the given index.
+    AirdropAlreadyClaimed = 60086,
+    /// The claimed amount exceeds the tokens left in the airdrop.
+    AirdropExhausted = 60087,
+    /// The voucher has expired.
+    VoucherExpired = 60088,
+    /// The signer of the voucher does not hold the `Minter` badge.
+    InvalidVoucherSigner = 60089,
+    /// The voucher signature is malformed or does not match the signer.
+    InvalidVoucherSignature = 60090,
+    /// The voucher nonce has already been redeemed for the signer.
+    VoucherAlreadyRedeemed = 60091,
+    /// The authorization is not valid yet.
+    AuthorizationNotYetValid = 60092,
+    /// The authorization has expired.
+    AuthorizationExpired = 60093,
+    /// The authorization signature is malformed or was not made by `from`.
+    InvalidAuthorizationSignature = 60094,
+    /// The authorization nonce has already been used or cancelled for the owner.
+    AuthorizationAlreadyUsed = 60095,
+    /// A metadata field is invalid, empty where it is required or longer than 1024 bytes.
+    InvalidMetadata = 60096,
+    /// More balances, allowances or holders were queried at once than allowed.
+    BatchTooLarge = 60097,
+    /// The flag to enable the holder registry is invalid.
+    InvalidHolderRegistryFlag = 60098,
+    /// The holder registry is disabled.
+    HolderRegistryDisabled = 60099,
+    /// The sum of all balances does not equal the total supply.
+    SupplyMismatch = 60100,
+    /// Outside the `MintBurn` mode, the airdrop cannot be escrowed from the contract's own
+    /// balance.
+    AirdropEscrowFromSelf = 60101,
+    /// In a wrapper mode tokens can only be minted against deposits, so the initial supply must be
+    /// zero and neither mint and burn, rebasing nor a legacy migration can be enabled.
+    InvalidWrapperSupply = 60102,
+    /// Streams cannot be created in the rebasing mode, since a rebase would change the value of
+    /// the escrowed shares but not the streamed amount.
+    StreamWithRebasing = 60103,
+    /// Outside the `MintBurn` mode, airdrops cannot be escrowed in the rebasing mode, since a
+    /// rebase would change the value of the escrowed shares but not the claimable amounts.
+    AirdropWithRebasing = 60104,
 }
 
 impl From<Cep18Error> for ApiError {
@@ -1,6 +1,6 @@
 use core::convert::TryFrom;
 
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{Key, U256};
 
@@ -31,6 +31,42 @@ pub enum Event {
     Transfer(Transfer),
     TransferFrom(TransferFrom),
     ChangeSecurity(ChangeSecurity),
+    TransferWithFee(TransferWithFee),
+    TransferFromWithFee(TransferFromWithFee),
+    ChangeTransferFee(ChangeTransferFee),
+    TransferWithMemo(TransferWithMemo),
+    Rebase(Rebase),
+    HoldCreated(HoldCreated),
+    HoldExecuted(HoldExecuted),
+    HoldReleased(HoldReleased),
+    StreamCreated(StreamCreated),
+    StreamWithdrawn(StreamWithdrawn),
+    StreamCancelled(StreamCancelled),
+    DividendDistributed(DividendDistributed),
+    DividendClaimed(DividendClaimed),
+    VaultDeposit(VaultDeposit),
+    VaultWithdraw(VaultWithdraw),
+    LegacyMigrated(LegacyMigrated),
+    ProposalCreated(ProposalCreated),
+    ProposalApproved(ProposalApproved),
+    ProposalExecuted(ProposalExecuted),
+    ChangeCouncil(ChangeCouncil),
+    Scheduled(Scheduled),
+    Executed(Executed),
+    Cancelled(Cancelled),
+    MerkleRootSet(MerkleRootSet),
+    AirdropClaimed(AirdropClaimed),
+    VoucherRedeemed(VoucherRedeemed),
+    AuthorizationCancelled(AuthorizationCancelled),
+    MetadataUpdated(MetadataUpdated),
+    BridgeMint(BridgeMint),
+    BridgeBurn(BridgeBurn),
+    ConfigureMinter(ConfigureMinter),
+    DecreaseMinterAllowance(DecreaseMinterAllowance),
+    ChangeMintWindow(ChangeMintWindow),
+    ChangeMaxBalance(ChangeMaxBalance),
+    ChangeDefaultTransferLimit(ChangeDefaultTransferLimit),
+    SetTransferLimit(SetTransferLimit),
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -89,6 +125,275 @@ pub struct ChangeSecurity {
     pub sec_change_map: BTreeMap<Key, SecurityBadge>,
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferWithFee {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub fee: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferFromWithFee {
+    pub spender: Key,
+    pub owner: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub fee: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferWithMemo {
+    pub sender: Key,
+    pub spender: Option<Key>,
+    pub recipient: Key,
+    pub amount: U256,
+    pub fee: U256,
+    pub memo: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeTransferFee {
+    pub admin: Key,
+    pub transfer_fee_bps: u32,
+    pub treasury: Key,
+    pub exemptions: BTreeMap<Key, bool>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Rebase {
+    pub rebaser: Key,
+    pub total_supply: U256,
+    pub rebase_index: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HoldCreated {
+    pub hold_id: String,
+    pub payer: Key,
+    pub recipient: Key,
+    pub notary: Key,
+    pub amount: U256,
+    pub expiration: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HoldExecuted {
+    pub hold_id: String,
+    pub payer: Key,
+    pub recipient: Key,
+    pub notary: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct HoldReleased {
+    pub hold_id: String,
+    pub payer: Key,
+    pub released_by: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamCreated {
+    pub stream_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamWithdrawn {
+    pub stream_id: u64,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamCancelled {
+    pub stream_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub cancelled_by: Key,
+    pub recipient_amount: U256,
+    pub sender_amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DividendDistributed {
+    pub distributor: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DividendClaimed {
+    pub owner: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VaultDeposit {
+    pub owner: Key,
+    pub assets: U256,
+    pub shares: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VaultWithdraw {
+    pub owner: Key,
+    pub assets: U256,
+    pub shares: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct LegacyMigrated {
+    pub owner: Key,
+    pub legacy_amount: U256,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Key,
+    pub action: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalApproved {
+    pub proposal_id: u64,
+    pub approver: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+    pub executor: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeCouncil {
+    pub council: Vec<Key>,
+    pub council_threshold: u32,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Scheduled {
+    pub operation_id: u64,
+    pub scheduler: Key,
+    pub action: String,
+    pub eta: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Executed {
+    pub operation_id: u64,
+    pub executor: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Cancelled {
+    pub operation_id: u64,
+    pub admin: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MerkleRootSet {
+    pub admin: Key,
+    pub root: [u8; 32],
+    pub total: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AirdropClaimed {
+    pub index: u64,
+    pub account: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VoucherRedeemed {
+    pub signer: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub nonce: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AuthorizationCancelled {
+    pub owner: Key,
+    pub nonce: [u8; 32],
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataUpdated {
+    pub admin: Key,
+    pub name: String,
+    pub symbol: String,
+    pub icon_url: Option<String>,
+    pub website: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BridgeMint {
+    pub bridge: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub external_ref: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BridgeBurn {
+    pub owner: Key,
+    pub amount: U256,
+    pub destination_chain: String,
+    pub destination_address: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ConfigureMinter {
+    pub admin: Key,
+    pub minter: Key,
+    pub allowance: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DecreaseMinterAllowance {
+    pub minter: Key,
+    pub allowance: U256,
+    pub decr_by: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeMintWindow {
+    pub admin: Key,
+    pub mint_window_seconds: u64,
+    pub mint_window_limit: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeMaxBalance {
+    pub admin: Key,
+    pub max_balance: U256,
+    pub exemptions: BTreeMap<Key, bool>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ChangeDefaultTransferLimit {
+    pub admin: Key,
+    pub daily_transfer_limit: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetTransferLimit {
+    pub admin: Key,
+    pub address: Key,
+    pub daily_transfer_limit: Option<U256>,
+}
+
 fn ces(event: Event) {
     match event {
         Event::Mint(ev) => emit(ev),
@@ -99,6 +404,42 @@ fn ces(event: Event) {
         Event::Transfer(ev) => emit(ev),
         Event::TransferFrom(ev) => emit(ev),
         Event::ChangeSecurity(ev) => emit(ev),
+        Event::TransferWithFee(ev) => emit(ev),
+        Event::TransferFromWithFee(ev) => emit(ev),
+        Event::ChangeTransferFee(ev) => emit(ev),
+        Event::TransferWithMemo(ev) => emit(ev),
+        Event::Rebase(ev) => emit(ev),
+        Event::HoldCreated(ev) => emit(ev),
+        Event::HoldExecuted(ev) => emit(ev),
+        Event::HoldReleased(ev) => emit(ev),
+        Event::StreamCreated(ev) => emit(ev),
+        Event::StreamWithdrawn(ev) => emit(ev),
+        Event::StreamCancelled(ev) => emit(ev),
+        Event::DividendDistributed(ev) => emit(ev),
+        Event::DividendClaimed(ev) => emit(ev),
+        Event::VaultDeposit(ev) => emit(ev),
+        Event::VaultWithdraw(ev) => emit(ev),
+        Event::LegacyMigrated(ev) => emit(ev),
+        Event::ProposalCreated(ev) => emit(ev),
+        Event::ProposalApproved(ev) => emit(ev),
+        Event::ProposalExecuted(ev) => emit(ev),
+        Event::ChangeCouncil(ev) => emit(ev),
+        Event::Scheduled(ev) => emit(ev),
+        Event::Executed(ev) => emit(ev),
+        Event::Cancelled(ev) => emit(ev),
+        Event::MerkleRootSet(ev) => emit(ev),
+        Event::AirdropClaimed(ev) => emit(ev),
+        Event::VoucherRedeemed(ev) => emit(ev),
+        Event::AuthorizationCancelled(ev) => emit(ev),
+        Event::MetadataUpdated(ev) => emit(ev),
+        Event::BridgeMint(ev) => emit(ev),
+        Event::BridgeBurn(ev) => emit(ev),
+        Event::ConfigureMinter(ev) => emit(ev),
+        Event::DecreaseMinterAllowance(ev) => emit(ev),
+        Event::ChangeMintWindow(ev) => emit(ev),
+        Event::ChangeMaxBalance(ev) => emit(ev),
+        Event::ChangeDefaultTransferLimit(ev) => emit(ev),
+        Event::SetTransferLimit(ev) => emit(ev),
     }
 }
 
@@ -115,7 +456,43 @@ pub fn init_events() {
             .with::<DecreaseAllowance>()
             .with::<Transfer>()
             .with::<TransferFrom>()
-            .with::<ChangeSecurity>();
+            .with::<ChangeSecurity>()
+            .with::<TransferWithFee>()
+            .with::<TransferFromWithFee>()
+            .with::<ChangeTransferFee>()
+            .with::<TransferWithMemo>()
+            .with::<Rebase>()
+            .with::<HoldCreated>()
+            .with::<HoldExecuted>()
+            .with::<HoldReleased>()
+            .with::<StreamCreated>()
+            .with::<StreamWithdrawn>()
+            .with::<StreamCancelled>()
+            .with::<DividendDistributed>()
+            .with::<DividendClaimed>()
+            .with::<VaultDeposit>()
+            .with::<VaultWithdraw>()
+            .with::<LegacyMigrated>()
+            .with::<ProposalCreated>()
+            .with::<ProposalApproved>()
+            .with::<ProposalExecuted>()
+            .with::<ChangeCouncil>()
+            .with::<Scheduled>()
+            .with::<Executed>()
+            .with::<Cancelled>()
+            .with::<MerkleRootSet>()
+            .with::<AirdropClaimed>()
+            .with::<VoucherRedeemed>()
+            .with::<AuthorizationCancelled>()
+            .with::<MetadataUpdated>()
+            .with::<BridgeMint>()
+            .with::<BridgeBurn>()
+            .with::<ConfigureMinter>()
+            .with::<DecreaseMinterAllowance>()
+            .with::<ChangeMintWindow>()
+            .with::<ChangeMaxBalance>()
+            .with::<ChangeDefaultTransferLimit>()
+            .with::<SetTransferLimit>();
         casper_event_standard::init(schemas);
     }
 }
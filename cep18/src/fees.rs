@@ -0,0 +1,93 @@
+//! Implementation of the optional transfer fee.
+use alloc::collections::BTreeMap;
+
+use casper_contract::{
+    contract_api::storage::{self, dictionary_get, dictionary_put},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::transfer_balance,
+    constants::{
+        BASIS_POINTS, ENABLE_TRANSFER_FEE, FEE_EXEMPTIONS, MAX_TRANSFER_FEE_BPS, TRANSFER_FEE_BPS,
+        TRANSFER_FEE_TREASURY,
+    },
+    error::Cep18Error,
+    modalities::TransferFee,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Returns `true` if the transfer fee mode is enabled for this contract instance.
+pub(crate) fn is_transfer_fee_enabled() -> bool {
+    read_optional_from::<u8>(ENABLE_TRANSFER_FEE).unwrap_or_default() == TransferFee::Enabled as u8
+}
+
+/// Returns `true` if the given account or contract does not pay or trigger transfer fees.
+pub(crate) fn is_fee_exempt(address: Key) -> bool {
+    dictionary_get::<bool>(
+        get_uref(FEE_EXEMPTIONS),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+/// Writes the given exemptions into the `fee_exemptions` dictionary.
+pub(crate) fn change_fee_exemptions(exemptions: &BTreeMap<Key, bool>) {
+    let exemptions_uref = get_uref(FEE_EXEMPTIONS);
+    for (&address, &exempt) in exemptions {
+        dictionary_put(
+            exemptions_uref,
+            &utils::make_dictionary_item_key(address),
+            exempt,
+        );
+    }
+}
+
+/// Checks that a transfer fee rate does not exceed [`MAX_TRANSFER_FEE_BPS`].
+pub(crate) fn validate_transfer_fee_bps(transfer_fee_bps: u32) -> Result<(), Cep18Error> {
+    if transfer_fee_bps > MAX_TRANSFER_FEE_BPS {
+        return Err(Cep18Error::InvalidTransferFee);
+    }
+    Ok(())
+}
+
+/// Writes a new transfer fee rate, reverting if it is out of bounds.
+pub(crate) fn write_transfer_fee_bps(transfer_fee_bps: u32) {
+    validate_transfer_fee_bps(transfer_fee_bps).unwrap_or_revert();
+    storage::write(get_uref(TRANSFER_FEE_BPS), transfer_fee_bps);
+}
+
+/// Computes the fee owed on a transfer of `amount` from `sender` to `recipient`.
+///
+/// No fee is charged when the fee mode is disabled or either side of the transfer is exempt.
+fn compute_fee(sender: Key, recipient: Key, amount: U256) -> Result<U256, Cep18Error> {
+    if !is_transfer_fee_enabled() || is_fee_exempt(sender) || is_fee_exempt(recipient) {
+        return Ok(U256::zero());
+    }
+    let transfer_fee_bps = read_from::<u32>(TRANSFER_FEE_BPS);
+    let fee = amount
+        .checked_mul(U256::from(transfer_fee_bps))
+        .ok_or(Cep18Error::Overflow)?
+        / U256::from(BASIS_POINTS);
+    Ok(fee)
+}
+
+/// Transfers `amount` from `sender`, routing the fee to the treasury and the remainder to the
+/// `recipient`. Returns the fee charged.
+///
+/// Like [`transfer_balance`], this function does not validate the sender.
+pub(crate) fn transfer_with_fee(
+    sender: Key,
+    recipient: Key,
+    amount: U256,
+) -> Result<U256, Cep18Error> {
+    let fee = compute_fee(sender, recipient, amount)?;
+    if !fee.is_zero() {
+        let treasury = read_from::<Key>(TRANSFER_FEE_TREASURY);
+        transfer_balance(sender, treasury, fee)?;
+    }
+    transfer_balance(sender, recipient, amount - fee)?;
+    Ok(fee)
+}
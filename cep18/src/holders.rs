@@ -0,0 +1,88 @@
+//! Implementation of the optional holder registry, which enumerates the accounts and contracts with
+//! a non-zero balance.
+//!
+//! Holders are stored in the `holders` dictionary under their index, from zero to `holder_count`
+//! exclusive. The `holder_indexes` dictionary maps each holder back to its index plus one, so that
+//! zero means the address does not hold any tokens. A holder whose balance drops to zero is
+//! replaced by the last holder, so the order of the holders is not stable.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::storage::{self, dictionary_get, dictionary_put},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ENABLE_HOLDER_REGISTRY, HOLDERS, HOLDER_COUNT, HOLDER_INDEXES},
+    modalities::HolderRegistry,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Returns `true` if the holder registry is enabled for this contract instance.
+pub(crate) fn is_holder_registry_enabled() -> bool {
+    read_optional_from::<u8>(ENABLE_HOLDER_REGISTRY).unwrap_or_default()
+        == HolderRegistry::Enabled as u8
+}
+
+/// Returns the number of addresses with a non-zero balance.
+pub(crate) fn read_holder_count() -> u64 {
+    read_from(HOLDER_COUNT)
+}
+
+fn read_holder(index: u64) -> Key {
+    dictionary_get(get_uref(HOLDERS), &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn write_holder(index: u64, holder: Key) {
+    dictionary_put(get_uref(HOLDERS), &index.to_string(), holder);
+}
+
+fn read_position(address: Key) -> u64 {
+    dictionary_get(
+        get_uref(HOLDER_INDEXES),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn write_position(address: Key, position: u64) {
+    dictionary_put(
+        get_uref(HOLDER_INDEXES),
+        &utils::make_dictionary_item_key(address),
+        position,
+    );
+}
+
+/// Adds `address` to the registry when its balance becomes non-zero and removes it when its
+/// balance drops to zero. Does nothing if the holder registry is disabled.
+pub(crate) fn update_holder(address: Key, new_balance: U256) {
+    if !is_holder_registry_enabled() {
+        return;
+    }
+    let position = read_position(address);
+    let holder_count = read_holder_count();
+    if position == 0 && !new_balance.is_zero() {
+        write_holder(holder_count, address);
+        write_position(address, holder_count + 1);
+        storage::write(get_uref(HOLDER_COUNT), holder_count + 1);
+    } else if position != 0 && new_balance.is_zero() {
+        let last_index = holder_count - 1;
+        if position - 1 != last_index {
+            let last_holder = read_holder(last_index);
+            write_holder(position - 1, last_holder);
+            write_position(last_holder, position);
+        }
+        write_position(address, 0);
+        storage::write(get_uref(HOLDER_COUNT), last_index);
+    }
+}
+
+/// Returns at most `limit` holders, starting from the holder with index `start`.
+pub(crate) fn read_holders(start: u64, limit: u64) -> Vec<Key> {
+    let end = start.saturating_add(limit).min(read_holder_count());
+    (start..end).map(read_holder).collect()
+}
@@ -0,0 +1,240 @@
+//! Implementation of holds, which reserve part of a balance until a notary executes or releases
+//! them.
+//!
+//! Held tokens stay in the balance of the payer but are excluded from what it can spend. The
+//! total held amount of every account is kept in the `balances_on_hold` dictionary, and each hold
+//! is kept in the `holds` dictionary under its id.
+use alloc::{vec, vec::Vec};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+use crate::{
+    balances::{get_balances_uref, read_balance_from, transfer_balance},
+    constants::{BALANCES_ON_HOLD, HOLDS},
+    error::Cep18Error,
+    rebasing,
+    utils::{self, get_uref},
+};
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum HoldStatus {
+    Ordered = 0,
+    Executed = 1,
+    Released = 2,
+}
+
+impl ToBytes for HoldStatus {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        Ok(vec![*self as u8])
+    }
+
+    fn serialized_length(&self) -> usize {
+        1
+    }
+}
+
+impl FromBytes for HoldStatus {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (status, remainder) = u8::from_bytes(bytes)?;
+        let status = match status {
+            0 => HoldStatus::Ordered,
+            1 => HoldStatus::Executed,
+            2 => HoldStatus::Released,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((status, remainder))
+    }
+}
+
+/// A hold of `amount` tokens of `payer` in favor of `recipient`, to be executed or released by
+/// `notary`. An `expiration` of zero means that the hold never expires.
+#[derive(Clone, Copy)]
+pub(crate) struct Hold {
+    pub payer: Key,
+    pub recipient: Key,
+    pub notary: Key,
+    pub amount: U256,
+    pub expiration: u64,
+    pub status: HoldStatus,
+}
+
+impl Hold {
+    fn is_expired(&self) -> bool {
+        self.expiration != 0 && u64::from(runtime::get_blocktime()) >= self.expiration
+    }
+}
+
+impl CLTyped for Hold {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Hold {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.payer.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.notary.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.expiration.to_bytes()?);
+        result.append(&mut self.status.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.payer.serialized_length()
+            + self.recipient.serialized_length()
+            + self.notary.serialized_length()
+            + self.amount.serialized_length()
+            + self.expiration.serialized_length()
+            + self.status.serialized_length()
+    }
+}
+
+impl FromBytes for Hold {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (payer, remainder) = Key::from_bytes(bytes)?;
+        let (recipient, remainder) = Key::from_bytes(remainder)?;
+        let (notary, remainder) = Key::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (expiration, remainder) = u64::from_bytes(remainder)?;
+        let (status, remainder) = HoldStatus::from_bytes(remainder)?;
+        let hold = Hold {
+            payer,
+            recipient,
+            notary,
+            amount,
+            expiration,
+            status,
+        };
+        Ok((hold, remainder))
+    }
+}
+
+fn read_hold(hold_id: &str) -> Option<Hold> {
+    dictionary_get(
+        get_uref(HOLDS),
+        &utils::make_string_dictionary_item_key(hold_id),
+    )
+    .unwrap_or_revert()
+}
+
+fn write_hold(hold_id: &str, hold: &Hold) {
+    dictionary_put(
+        get_uref(HOLDS),
+        &utils::make_string_dictionary_item_key(hold_id),
+        *hold,
+    )
+}
+
+/// Reads the number of tokens of `address` that are on hold.
+///
+/// Contracts upgraded from a version without holds have no tokens on hold.
+pub(crate) fn read_balance_on_hold(address: Key) -> U256 {
+    if runtime::get_key(BALANCES_ON_HOLD).is_none() {
+        return U256::zero();
+    }
+    dictionary_get(
+        get_uref(BALANCES_ON_HOLD),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn write_balance_on_hold(address: Key, amount: U256) {
+    dictionary_put(
+        get_uref(BALANCES_ON_HOLD),
+        &utils::make_dictionary_item_key(address),
+        amount,
+    )
+}
+
+/// Checks that a new balance of `address`, given in stored units, still covers its tokens on hold.
+pub(crate) fn check_spendable(address: Key, new_balance: U256) -> Result<(), Cep18Error> {
+    if rebasing::shares_to_amount(new_balance)? < read_balance_on_hold(address) {
+        return Err(Cep18Error::InsufficientBalance);
+    }
+    Ok(())
+}
+
+/// Puts `hold.amount` tokens of `hold.payer` on hold under `hold_id`, which must not have been used
+/// before.
+pub(crate) fn create_hold(hold_id: &str, hold: &Hold) -> Result<(), Cep18Error> {
+    if read_hold(hold_id).is_some() {
+        return Err(Cep18Error::HoldAlreadyExists);
+    }
+    let balance_on_hold = read_balance_on_hold(hold.payer)
+        .checked_add(hold.amount)
+        .ok_or(Cep18Error::Overflow)?;
+    let balance = rebasing::shares_to_amount(read_balance_from(get_balances_uref(), hold.payer))?;
+    if balance < balance_on_hold {
+        return Err(Cep18Error::InsufficientBalance);
+    }
+    write_balance_on_hold(hold.payer, balance_on_hold);
+    write_hold(hold_id, hold);
+    Ok(())
+}
+
+/// Reads the hold with the given id, failing unless it is still ordered.
+fn read_ordered_hold(hold_id: &str) -> Result<Hold, Cep18Error> {
+    let hold = read_hold(hold_id).ok_or(Cep18Error::HoldNotFound)?;
+    if hold.status != HoldStatus::Ordered {
+        return Err(Cep18Error::HoldNotActive);
+    }
+    Ok(hold)
+}
+
+/// Marks the hold as finished with `status` and returns its tokens to the payer's spendable
+/// balance.
+fn close_hold(hold_id: &str, hold: &mut Hold, status: HoldStatus) {
+    hold.status = status;
+    write_hold(hold_id, hold);
+    write_balance_on_hold(
+        hold.payer,
+        read_balance_on_hold(hold.payer).saturating_sub(hold.amount),
+    );
+}
+
+/// Executes the hold on behalf of `caller`, which must be its notary, transferring the held
+/// tokens to the recipient. Expired holds can no longer be executed.
+pub(crate) fn execute_hold(hold_id: &str, caller: Key) -> Result<Hold, Cep18Error> {
+    let mut hold = read_ordered_hold(hold_id)?;
+    if caller != hold.notary {
+        return Err(Cep18Error::InsufficientRights);
+    }
+    if hold.is_expired() {
+        return Err(Cep18Error::HoldExpired);
+    }
+    close_hold(hold_id, &mut hold, HoldStatus::Executed);
+    transfer_balance(hold.payer, hold.recipient, hold.amount)?;
+    Ok(hold)
+}
+
+/// Releases the hold on behalf of `caller` without transferring the held tokens. The notary and
+/// the recipient can release a hold at any time, the payer only once it has expired.
+pub(crate) fn release_hold(hold_id: &str, caller: Key) -> Result<Hold, Cep18Error> {
+    let mut hold = read_ordered_hold(hold_id)?;
+    if caller != hold.notary && caller != hold.recipient {
+        if caller != hold.payer {
+            return Err(Cep18Error::InsufficientRights);
+        }
+        if !hold.is_expired() {
+            return Err(Cep18Error::HoldNotExpired);
+        }
+    }
+    close_hold(hold_id, &mut hold, HoldStatus::Released);
+    Ok(hold)
+}
@@ -0,0 +1,72 @@
+//! Implementation of the migration from a legacy CEP-18 token, in which holders swap their legacy
+//! tokens for tokens of this contract at a fixed ratio.
+//!
+//! Migrated legacy tokens are pulled with `transfer_from` to the contract's own package key, which
+//! never sends them on, so they stay locked and cannot be migrated twice. An admin can close the
+//! migration, after which it cannot be reopened.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::{
+    constants::{
+        AMOUNT, LEGACY_MIGRATION_OPEN, LEGACY_RATIO_DENOMINATOR, LEGACY_RATIO_NUMERATOR,
+        LEGACY_TOKEN, OWNER, RECIPIENT, TRANSFER_FROM_ENTRY_POINT_NAME,
+    },
+    error::Cep18Error,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Returns the legacy token, reverting if this contract instance has none.
+fn read_legacy_token() -> Key {
+    read_optional_from::<Key>(LEGACY_TOKEN)
+        .unwrap_or_revert_with(Cep18Error::LegacyMigrationDisabled)
+}
+
+/// Checks that the migration is configured and still open.
+pub(crate) fn check_migration_open() -> Result<(), Cep18Error> {
+    match read_optional_from::<bool>(LEGACY_MIGRATION_OPEN) {
+        None => Err(Cep18Error::LegacyMigrationDisabled),
+        Some(false) => Err(Cep18Error::LegacyMigrationClosed),
+        Some(true) => Ok(()),
+    }
+}
+
+/// Closes the migration for good.
+pub(crate) fn close_migration() {
+    check_migration_open().unwrap_or_revert();
+    storage::write(get_uref(LEGACY_MIGRATION_OPEN), false);
+}
+
+/// Checks that a conversion ratio has neither a zero numerator nor a zero denominator.
+pub(crate) fn validate_ratio(numerator: U256, denominator: U256) -> Result<(), Cep18Error> {
+    if numerator.is_zero() || denominator.is_zero() {
+        return Err(Cep18Error::InvalidLegacyRatio);
+    }
+    Ok(())
+}
+
+/// Converts a number of legacy tokens into tokens of this contract, rounded down.
+pub(crate) fn convert(legacy_amount: U256) -> Result<U256, Cep18Error> {
+    let amount = legacy_amount
+        .checked_mul(read_from::<U256>(LEGACY_RATIO_NUMERATOR))
+        .ok_or(Cep18Error::Overflow)?
+        / read_from::<U256>(LEGACY_RATIO_DENOMINATOR);
+    if amount.is_zero() {
+        return Err(Cep18Error::InvalidLegacyAmount);
+    }
+    Ok(amount)
+}
+
+/// Locks `legacy_amount` legacy tokens of `owner` under the contract's own package key, which
+/// requires this contract package to have been approved to spend them.
+pub(crate) fn lock_legacy_tokens(owner: Key, legacy_amount: U256) {
+    utils::call_token::<()>(
+        read_legacy_token(),
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER => owner,
+            RECIPIENT => utils::get_self_key(),
+            AMOUNT => legacy_amount,
+        },
+    )
+}
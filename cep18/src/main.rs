@@ -3,14 +3,37 @@
 
 extern crate alloc;
 
+use core::convert::TryFrom;
+
+mod airdrop;
 mod allowances;
+mod authorizations;
 mod balances;
+mod bridge;
 pub mod constants;
+mod council;
+mod dividends;
 pub mod entry_points;
 mod error;
 mod events;
+mod fees;
+mod holders;
+mod holds;
+mod legacy;
+mod max_balance;
+mod metadata;
+mod mint_window;
+mod minter_allowances;
 mod modalities;
+mod rebasing;
+mod streams;
+mod supply;
+mod timelock;
+mod token_info;
+mod transfer_limits;
 mod utils;
+mod vouchers;
+mod wrapper;
 
 use alloc::{
     collections::BTreeMap,
@@ -21,32 +44,70 @@ use alloc::{
 };
 
 use allowances::{get_allowances_uref, read_allowance_from, write_allowance_to};
-use balances::{get_balances_uref, read_balance_from, transfer_balance, write_balance_to};
+use balances::{get_balances_uref, read_balance_from, write_balance_to};
 use entry_points::generate_entry_points;
 
 use casper_contract::{
     contract_api::{
         runtime::{self, get_caller, get_key, get_named_arg, put_key, revert},
         storage::{self, dictionary_put},
+        system,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::ToBytes, contracts::NamedKeys, runtime_args, CLValue, ContractHash,
-    ContractPackageHash, Key, RuntimeArgs, U256,
+    bytesrepr::{Bytes, ToBytes},
+    contracts::NamedKeys,
+    runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, PublicKey,
+    RuntimeArgs, URef, U256,
 };
 
 use constants::{
-    ACCESS_KEY_NAME_PREFIX, ADDRESS, ADMIN_LIST, ALLOWANCES, AMOUNT, BALANCES,
-    CONTRACT_NAME_PREFIX, CONTRACT_VERSION_PREFIX, DECIMALS, ENABLE_MINT_BURN, EVENTS_MODE,
-    HASH_KEY_NAME_PREFIX, INIT_ENTRY_POINT_NAME, MINTER_LIST, NAME, NONE_LIST, OWNER, PACKAGE_HASH,
-    RECIPIENT, SECURITY_BADGES, SPENDER, SYMBOL, TOTAL_SUPPLY,
+    ACCESS_KEY_NAME_PREFIX, ACCOUNT, ACTION, ADDRESS, ADDRESSES, ADMIN_LIST, AIRDROP_CLAIMED,
+    AIRDROP_EPOCH, AIRDROP_REMAINING, ALLOWANCE, ALLOWANCES, AMOUNT, ARGS, ASSETS,
+    AUTHORIZATION_STATES, BALANCES, BALANCES_ON_HOLD, BRIDGE_LIST,
+    CHANGE_MAX_BALANCE_ENTRY_POINT_NAME, CHANGE_MINT_WINDOW_ENTRY_POINT_NAME,
+    CHANGE_SECURITY_ENTRY_POINT_NAME, CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME,
+    CONFIGURE_MINTER_ENTRY_POINT_NAME, CONTRACT_NAME_PREFIX, CONTRACT_VERSION_PREFIX, COUNCIL,
+    COUNCIL_THRESHOLD, DAILY_TRANSFERS, DAILY_TRANSFER_LIMIT, DECIMALS, DESCRIPTION,
+    DESTINATION_ADDRESS, DESTINATION_CHAIN, DIVIDENDS_MODE, DIVIDEND_CORRECTIONS,
+    DIVIDEND_PER_SHARE, DIVIDEND_PURSE, DIVIDEND_TOKEN, ENABLE_HOLDER_REGISTRY, ENABLE_MAX_BALANCE,
+    ENABLE_MINTER_ALLOWANCES, ENABLE_MINT_BURN, ENABLE_REBASING, ENABLE_TRANSFER_FEE,
+    ENABLE_TRANSFER_LIMITS, END_TIME, EVENTS_MODE, EXEMPT_LIST, EXPIRATION, EXPIRY, EXTERNAL_REF,
+    FEE_EXEMPTIONS, FROM, HASH_KEY_NAME_PREFIX, HOLDERS, HOLDER_COUNT, HOLDER_INDEXES, HOLDS,
+    HOLD_ID, ICON_URL, INDEX, INIT_ENTRY_POINT_NAME, LEGACY_MIGRATION_OPEN,
+    LEGACY_RATIO_DENOMINATOR, LEGACY_RATIO_NUMERATOR, LEGACY_TOKEN, LIMIT, MAX_BALANCE,
+    MAX_BALANCE_EXEMPTIONS, MAX_BATCH_LENGTH, MAX_MEMO_LENGTH, MEMO, MERKLE_ROOT, METADATA, MINTER,
+    MINTER_ALLOWANCES, MINTER_LIST, MINT_WINDOW_LIMIT, MINT_WINDOW_MINTS, MINT_WINDOW_SECONDS,
+    NAME, NEW_TOTAL_SUPPLY, NONCE, NONE_LIST, NON_EXEMPT_LIST, NOTARY, OPERATION_COUNT,
+    OPERATION_ID, OWNER, PACKAGE_HASH, PAIRS, PROCESSED_REFS, PROOF, PROPOSALS, PROPOSAL_COUNT,
+    PROPOSAL_ID, PURSE, REBASER_LIST, REBASE_INDEX, REBASE_INDEX_PRECISION, RECIPIENT,
+    REDEEMED_VOUCHERS, ROOT, SCHEDULED_OPERATIONS, SECURITY_BADGES,
+    SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME, SET_TRANSFER_LIMIT_ENTRY_POINT_NAME, SHARES,
+    SIGNATURE, SIGNER_PUBLIC_KEY, SPENDER, START, START_TIME, STREAMS, STREAM_COUNT, STREAM_ID,
+    SYMBOL, TIMELOCK_DELAY_SECONDS, TIMELOCK_SCHEDULER, TO, TOTAL, TOTAL_BURNED, TOTAL_MINTED,
+    TOTAL_SUPPLY, TRANSFER_ENTRY_POINT_NAME, TRANSFER_FEE_BPS, TRANSFER_FEE_TREASURY,
+    TRANSFER_FROM_ENTRY_POINT_NAME, TRANSFER_LIMIT_OVERRIDES, UNDERLYING_TOKEN, VALID_AFTER,
+    VALID_BEFORE, WEBSITE, WRAPPER_MODE, WRAPPER_PURSE,
 };
 pub use error::Cep18Error;
 use events::{
-    init_events, Burn, ChangeSecurity, DecreaseAllowance, Event, IncreaseAllowance, Mint,
-    SetAllowance, Transfer, TransferFrom,
+    init_events, AirdropClaimed, AuthorizationCancelled, BridgeBurn, BridgeMint, Burn, Cancelled,
+    ChangeCouncil, ChangeDefaultTransferLimit, ChangeMaxBalance, ChangeMintWindow, ChangeSecurity,
+    ChangeTransferFee, ConfigureMinter, DecreaseAllowance, DecreaseMinterAllowance,
+    DividendClaimed, DividendDistributed, Event, Executed, HoldCreated, HoldExecuted, HoldReleased,
+    IncreaseAllowance, LegacyMigrated, MerkleRootSet, MetadataUpdated, Mint, ProposalApproved,
+    ProposalCreated, ProposalExecuted, Rebase, Scheduled, SetAllowance, SetTransferLimit,
+    StreamCancelled, StreamCreated, StreamWithdrawn, Transfer, TransferFrom, TransferFromWithFee,
+    TransferWithFee, TransferWithMemo, VaultDeposit, VaultWithdraw, VoucherRedeemed,
+};
+use holds::{Hold, HoldStatus};
+use modalities::{
+    DividendsMode, HolderRegistry, MaxBalance, MinterAllowances, Rebasing, TransferFee,
+    TransferLimits, WrapperMode,
 };
+use rebasing::Rounding;
+use streams::Stream;
 use utils::{
     get_immediate_caller_address, get_total_supply_uref, read_from, read_total_supply_from,
     sec_check, write_total_supply_to, SecurityBadge,
@@ -67,16 +128,81 @@ pub extern "C" fn decimals() {
     runtime::ret(CLValue::from_t(utils::read_from::<u8>(DECIMALS)).unwrap_or_revert());
 }
 
+/// Returns the display name and symbol of the token together with its extended metadata fields
+/// that are set.
+#[no_mangle]
+pub extern "C" fn token_metadata() {
+    runtime::ret(CLValue::from_t(metadata::read_metadata()).unwrap_or_revert());
+}
+
+/// Returns the metadata, modalities, contract version and schema version of the token in a single
+/// structured value.
+#[no_mangle]
+pub extern "C" fn token_info() {
+    runtime::ret(CLValue::from_t(token_info::read_token_info()).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to update the display name, symbol and extended metadata of the token. Only the
+/// fields that are passed are updated; an empty extended field is cleared.
+#[no_mangle]
+pub extern "C" fn set_metadata() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    for field in [NAME, SYMBOL] {
+        if let Some(value) =
+            utils::get_optional_named_arg_with_user_errors(field, Cep18Error::InvalidMetadata)
+        {
+            metadata::write_display_field(field, value).unwrap_or_revert();
+        }
+    }
+    for field in metadata::EXTENDED_FIELDS {
+        if let Some(value) =
+            utils::get_optional_named_arg_with_user_errors(field, Cep18Error::InvalidMetadata)
+        {
+            metadata::write_extended_field(field, value).unwrap_or_revert();
+        }
+    }
+    events::record_event_dictionary(Event::MetadataUpdated(MetadataUpdated {
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+        name: read_from(NAME),
+        symbol: read_from(SYMBOL),
+        icon_url: metadata::read_extended_field(ICON_URL),
+        website: metadata::read_extended_field(WEBSITE),
+        description: metadata::read_extended_field(DESCRIPTION),
+    }));
+}
+
 #[no_mangle]
 pub extern "C" fn total_supply() {
-    runtime::ret(CLValue::from_t(utils::read_from::<U256>(TOTAL_SUPPLY)).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(rebasing::read_total_supply_amount()).unwrap_or_revert());
+}
+
+/// Returns the number of tokens ever minted, including the initial supply.
+#[no_mangle]
+pub extern "C" fn total_minted() {
+    runtime::ret(CLValue::from_t(supply::read_total_minted()).unwrap_or_revert());
+}
+
+/// Returns the number of tokens ever burned.
+#[no_mangle]
+pub extern "C" fn total_burned() {
+    runtime::ret(CLValue::from_t(supply::read_total_burned()).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to verify that the balances of all holders add up to the total supply,
+/// reverting otherwise. Only available if the holder registry is enabled.
+#[no_mangle]
+pub extern "C" fn audit_supply() {
+    sec_check(vec![SecurityBadge::Admin]);
+    supply::audit().unwrap_or_revert();
 }
 
 #[no_mangle]
 pub extern "C" fn balance_of() {
     let address: Key = runtime::get_named_arg(ADDRESS);
     let balances_uref = get_balances_uref();
-    let balance = balances::read_balance_from(balances_uref, address);
+    let balance = rebasing::shares_to_amount(balances::read_balance_from(balances_uref, address))
+        .unwrap_or_revert();
     runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
 }
 
@@ -89,6 +215,67 @@ pub extern "C" fn allowance() {
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
+/// Returns the balances of `addresses`, in the same order. At most [`MAX_BATCH_LENGTH`] addresses
+/// can be queried at once.
+#[no_mangle]
+pub extern "C" fn balances_of() {
+    let addresses: Vec<Key> = runtime::get_named_arg(ADDRESSES);
+    if addresses.len() > MAX_BATCH_LENGTH {
+        revert(Cep18Error::BatchTooLarge);
+    }
+    let balances_uref = get_balances_uref();
+    let balances: Vec<U256> = addresses
+        .into_iter()
+        .map(|address| {
+            rebasing::shares_to_amount(balances::read_balance_from(balances_uref, address))
+                .unwrap_or_revert()
+        })
+        .collect();
+    runtime::ret(CLValue::from_t(balances).unwrap_or_revert());
+}
+
+/// Returns the allowances of the `(owner, spender)` `pairs`, in the same order. At most
+/// [`MAX_BATCH_LENGTH`] pairs can be queried at once.
+#[no_mangle]
+pub extern "C" fn allowances_of() {
+    let pairs: Vec<(Key, Key)> = runtime::get_named_arg(PAIRS);
+    if pairs.len() > MAX_BATCH_LENGTH {
+        revert(Cep18Error::BatchTooLarge);
+    }
+    let allowances_uref = get_allowances_uref();
+    let allowances: Vec<U256> = pairs
+        .into_iter()
+        .map(|(owner, spender)| read_allowance_from(allowances_uref, owner, spender))
+        .collect();
+    runtime::ret(CLValue::from_t(allowances).unwrap_or_revert());
+}
+
+/// Returns the number of addresses with a non-zero balance. Only available if the holder registry
+/// is enabled.
+#[no_mangle]
+pub extern "C" fn holder_count() {
+    if !holders::is_holder_registry_enabled() {
+        revert(Cep18Error::HolderRegistryDisabled);
+    }
+    runtime::ret(CLValue::from_t(holders::read_holder_count()).unwrap_or_revert());
+}
+
+/// Returns at most `limit` addresses with a non-zero balance, starting from the holder with index
+/// `start`. At most [`MAX_BATCH_LENGTH`] holders can be queried at once. Only available if the
+/// holder registry is enabled.
+#[no_mangle]
+pub extern "C" fn holders() {
+    if !holders::is_holder_registry_enabled() {
+        revert(Cep18Error::HolderRegistryDisabled);
+    }
+    let start: u64 = runtime::get_named_arg(START);
+    let limit: u64 = runtime::get_named_arg(LIMIT);
+    if limit > MAX_BATCH_LENGTH as u64 {
+        revert(Cep18Error::BatchTooLarge);
+    }
+    runtime::ret(CLValue::from_t(holders::read_holders(start, limit)).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn approve() {
     let owner = utils::get_immediate_caller_address().unwrap_or_revert();
@@ -146,34 +333,23 @@ pub extern "C" fn increase_allowance() {
     }))
 }
 
-#[no_mangle]
-pub extern "C" fn transfer() {
-    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
-    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+/// Moves `amount` tokens from `sender` to `recipient` and returns the fee charged.
+fn transfer_tokens(sender: Key, recipient: Key, amount: U256) -> U256 {
     if sender == recipient {
         revert(Cep18Error::CannotTargetSelfUser);
     }
-    let amount: U256 = runtime::get_named_arg(AMOUNT);
-
-    transfer_balance(sender, recipient, amount).unwrap_or_revert();
-    events::record_event_dictionary(Event::Transfer(Transfer {
-        sender,
-        recipient,
-        amount,
-    }))
+    fees::transfer_with_fee(sender, recipient, amount).unwrap_or_revert()
 }
 
-#[no_mangle]
-pub extern "C" fn transfer_from() {
-    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
-    let recipient: Key = runtime::get_named_arg(RECIPIENT);
-    let owner: Key = runtime::get_named_arg(OWNER);
+/// Moves `amount` tokens from `owner` to `recipient` on behalf of `spender`, deducting them from
+/// the allowance of `spender`. Returns the fee charged, or `None` if the amount is zero and nothing
+/// was transferred.
+fn transfer_tokens_from(spender: Key, owner: Key, recipient: Key, amount: U256) -> Option<U256> {
     if owner == recipient {
         revert(Cep18Error::CannotTargetSelfUser);
     }
-    let amount: U256 = runtime::get_named_arg(AMOUNT);
     if amount.is_zero() {
-        return;
+        return None;
     }
 
     let allowances_uref = get_allowances_uref();
@@ -183,16 +359,180 @@ pub extern "C" fn transfer_from() {
         .ok_or(Cep18Error::InsufficientAllowance)
         .unwrap_or_revert();
 
-    transfer_balance(owner, recipient, amount).unwrap_or_revert();
+    let fee = fees::transfer_with_fee(owner, recipient, amount).unwrap_or_revert();
     write_allowance_to(allowances_uref, owner, spender, new_spender_allowance);
-    events::record_event_dictionary(Event::TransferFrom(TransferFrom {
-        spender,
-        owner,
+    Some(fee)
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    let fee = transfer_tokens(sender, recipient, amount);
+    if fee.is_zero() {
+        events::record_event_dictionary(Event::Transfer(Transfer {
+            sender,
+            recipient,
+            amount,
+        }))
+    } else {
+        events::record_event_dictionary(Event::TransferWithFee(TransferWithFee {
+            sender,
+            recipient,
+            amount,
+            fee,
+        }))
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    let fee = match transfer_tokens_from(spender, owner, recipient, amount) {
+        Some(fee) => fee,
+        None => return,
+    };
+    if fee.is_zero() {
+        events::record_event_dictionary(Event::TransferFrom(TransferFrom {
+            spender,
+            owner,
+            recipient,
+            amount,
+        }))
+    } else {
+        events::record_event_dictionary(Event::TransferFromWithFee(TransferFromWithFee {
+            spender,
+            owner,
+            recipient,
+            amount,
+            fee,
+        }))
+    }
+}
+
+/// Like `transfer`, but attaches a `memo`, e.g. a payment reference, to the emitted
+/// `TransferWithMemo` event.
+#[no_mangle]
+pub extern "C" fn transfer_with_memo() {
+    let sender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let memo = read_memo();
+
+    let fee = transfer_tokens(sender, recipient, amount);
+    events::record_event_dictionary(Event::TransferWithMemo(TransferWithMemo {
+        sender,
+        spender: None,
         recipient,
         amount,
+        fee,
+        memo,
     }))
 }
 
+/// Like `transfer_from`, but attaches a `memo`, e.g. a payment reference, to the emitted
+/// `TransferWithMemo` event.
+#[no_mangle]
+pub extern "C" fn transfer_from_with_memo() {
+    let spender = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let memo = read_memo();
+
+    let fee = match transfer_tokens_from(spender, owner, recipient, amount) {
+        Some(fee) => fee,
+        None => return,
+    };
+    events::record_event_dictionary(Event::TransferWithMemo(TransferWithMemo {
+        sender: owner,
+        spender: Some(spender),
+        recipient,
+        amount,
+        fee,
+        memo,
+    }))
+}
+
+/// Reads the `memo` runtime argument, reverting if it is longer than [`MAX_MEMO_LENGTH`] bytes.
+fn read_memo() -> String {
+    let memo: String = utils::get_named_arg_with_user_errors(
+        MEMO,
+        Cep18Error::InvalidMemo,
+        Cep18Error::InvalidMemo,
+    )
+    .unwrap_or_revert();
+    if memo.len() > MAX_MEMO_LENGTH {
+        revert(Cep18Error::InvalidMemo);
+    }
+    memo
+}
+
+/// Transfers `amount` tokens from `from` to `to` as authorized off-chain by `from`, who signs the
+/// transfer with `signer_public_key`. Can be called by anyone, e.g. a relayer paying for the gas,
+/// between the `valid_after` and `valid_before` block times.
+#[no_mangle]
+pub extern "C" fn transfer_with_authorization() {
+    let from: Key = runtime::get_named_arg(FROM);
+    let to: Key = runtime::get_named_arg(TO);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let valid_after: u64 = runtime::get_named_arg(VALID_AFTER);
+    let valid_before: u64 = runtime::get_named_arg(VALID_BEFORE);
+    let nonce: [u8; 32] = runtime::get_named_arg(NONCE);
+    let signer_public_key: PublicKey = runtime::get_named_arg(SIGNER_PUBLIC_KEY);
+    let signature: Bytes = runtime::get_named_arg(SIGNATURE);
+
+    authorizations::use_transfer_authorization(
+        from,
+        to,
+        amount,
+        valid_after,
+        valid_before,
+        nonce,
+        &signer_public_key,
+        &signature,
+    )
+    .unwrap_or_revert();
+    let fee = transfer_tokens(from, to, amount);
+    if fee.is_zero() {
+        events::record_event_dictionary(Event::Transfer(Transfer {
+            sender: from,
+            recipient: to,
+            amount,
+        }))
+    } else {
+        events::record_event_dictionary(Event::TransferWithFee(TransferWithFee {
+            sender: from,
+            recipient: to,
+            amount,
+            fee,
+        }))
+    }
+}
+
+/// Cancels the unused authorization of `from` with the given `nonce`, as signed by `from` with
+/// `signer_public_key`. Can be called by anyone.
+#[no_mangle]
+pub extern "C" fn cancel_authorization() {
+    let from: Key = runtime::get_named_arg(FROM);
+    let nonce: [u8; 32] = runtime::get_named_arg(NONCE);
+    let signer_public_key: PublicKey = runtime::get_named_arg(SIGNER_PUBLIC_KEY);
+    let signature: Bytes = runtime::get_named_arg(SIGNATURE);
+
+    authorizations::cancel_authorization(from, nonce, &signer_public_key, &signature)
+        .unwrap_or_revert();
+    events::record_event_dictionary(Event::AuthorizationCancelled(AuthorizationCancelled {
+        owner: from,
+        nonce,
+    }));
+}
+
 #[no_mangle]
 pub extern "C" fn mint() {
     if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
@@ -204,28 +544,21 @@ pub extern "C" fn mint() {
     let owner: Key = runtime::get_named_arg(OWNER);
     let amount: U256 = runtime::get_named_arg(AMOUNT);
 
-    let balances_uref = get_balances_uref();
-    let total_supply_uref = get_total_supply_uref();
-    let new_balance = {
-        let balance = read_balance_from(balances_uref, owner);
-        balance
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    let new_total_supply = {
-        let total_supply: U256 = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)
-            .unwrap_or_revert()
-    };
-    write_balance_to(balances_uref, owner, new_balance);
-    write_total_supply_to(total_supply_uref, new_total_supply);
-    events::record_event_dictionary(Event::Mint(Mint {
-        recipient: owner,
-        amount,
-    }))
+    let minter = get_immediate_caller_address().unwrap_or_revert();
+    if minter_allowances::is_minter_allowances_enabled()
+        && utils::read_sec_badge(minter) == Some(SecurityBadge::Minter)
+    {
+        let allowance =
+            minter_allowances::spend_minter_allowance(minter, amount).unwrap_or_revert();
+        events::record_event_dictionary(Event::DecreaseMinterAllowance(DecreaseMinterAllowance {
+            minter,
+            allowance,
+            decr_by: amount,
+        }));
+    }
+    mint_window::record_mint(amount).unwrap_or_revert();
+
+    mint_tokens(owner, amount);
 }
 
 #[no_mangle]
@@ -241,129 +574,1194 @@ pub extern "C" fn burn() {
     }
 
     let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    burn_tokens(owner, amount);
+}
+
+/// Credits `amount` newly created tokens to `owner`, increasing the total supply. In the `Cep18`
+/// wrapper mode the new supply must be backed by the underlying tokens held by the contract,
+/// whichever way the tokens are minted.
+///
+/// This function does not check whether the caller is allowed to mint.
+fn mint_tokens(owner: Key, amount: U256) {
+    let shares = rebasing::amount_to_shares(amount, Rounding::Down).unwrap_or_revert();
+
     let balances_uref = get_balances_uref();
     let total_supply_uref = get_total_supply_uref();
-    let new_balance = {
-        let balance = read_balance_from(balances_uref, owner);
-        balance
-            .checked_sub(amount)
-            .ok_or(Cep18Error::InsufficientBalance)
+    let balance = read_balance_from(balances_uref, owner);
+    let new_balance = balance
+        .checked_add(shares)
+        .ok_or(Cep18Error::Overflow)
+        .unwrap_or_revert();
+    max_balance::check_max_balance(owner, new_balance).unwrap_or_revert();
+    dividends::update_correction(owner, balance, new_balance).unwrap_or_revert();
+    let new_total_supply = {
+        let total_supply: U256 = read_total_supply_from(total_supply_uref);
+        total_supply
+            .checked_add(shares)
+            .ok_or(Cep18Error::Overflow)
             .unwrap_or_revert()
     };
+    supply::record_mint(amount).unwrap_or_revert();
+    write_balance_to(balances_uref, owner, new_balance);
+    write_total_supply_to(total_supply_uref, new_total_supply);
+    if wrapper::read_wrapper_mode() == WrapperMode::Cep18 {
+        wrapper::check_backing().unwrap_or_revert();
+    }
+    events::record_event_dictionary(Event::Mint(Mint {
+        recipient: owner,
+        amount,
+    }))
+}
+
+/// Destroys `amount` tokens held by `owner`, decreasing the total supply.
+///
+/// This function does not check whether the caller is allowed to burn.
+fn burn_tokens(owner: Key, amount: U256) {
+    let shares = rebasing::amount_to_shares(amount, Rounding::Up).unwrap_or_revert();
+    let balances_uref = get_balances_uref();
+    let total_supply_uref = get_total_supply_uref();
+    let balance = read_balance_from(balances_uref, owner);
+    let new_balance = balance
+        .checked_sub(shares)
+        .ok_or(Cep18Error::InsufficientBalance)
+        .unwrap_or_revert();
+    holds::check_spendable(owner, new_balance).unwrap_or_revert();
+    dividends::update_correction(owner, balance, new_balance).unwrap_or_revert();
     let new_total_supply = {
         let total_supply = read_total_supply_from(total_supply_uref);
         total_supply
-            .checked_sub(amount)
+            .checked_sub(shares)
             .ok_or(Cep18Error::Overflow)
             .unwrap_or_revert()
     };
+    supply::record_burn(amount).unwrap_or_revert();
     write_balance_to(balances_uref, owner, new_balance);
     write_total_supply_to(total_supply_uref, new_total_supply);
     events::record_event_dictionary(Event::Burn(Burn { owner, amount }))
 }
 
-/// Initiates the contracts states. Only used by the installer call,
-/// later calls will cause it to revert.
+/// Wraps CSPR: moves `amount` motes from the given purse into the contract purse and mints the
+/// same amount of tokens to the caller. In the `Vault` wrapper mode, takes `amount` underlying
+/// tokens from the caller instead and mints the shares the received tokens are worth, rounded down,
+/// and in the `Cep18` wrapper mode it is the same as `wrap`.
 #[no_mangle]
-pub extern "C" fn init() {
-    if get_key(ALLOWANCES).is_some() {
-        revert(Cep18Error::AlreadyInitialized);
-    }
-    let package_hash = get_named_arg::<Key>(PACKAGE_HASH);
-    put_key(PACKAGE_HASH, package_hash);
-    storage::new_dictionary(ALLOWANCES).unwrap_or_revert();
-    let balances_uref = storage::new_dictionary(BALANCES).unwrap_or_revert();
-    let initial_supply = runtime::get_named_arg(TOTAL_SUPPLY);
-    let caller = get_caller();
-    write_balance_to(balances_uref, caller.into(), initial_supply);
-
-    let security_badges_dict = storage::new_dictionary(SECURITY_BADGES).unwrap_or_revert();
-    dictionary_put(
-        security_badges_dict,
-        &base64::encode(Key::from(get_caller()).to_bytes().unwrap_or_revert()),
-        SecurityBadge::Admin,
-    );
-
-    let admin_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
-    let minter_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
-
-    init_events();
+pub extern "C" fn deposit() {
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
 
-    if let Some(minter_list) = minter_list {
-        for minter in minter_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64::encode(minter.to_bytes().unwrap_or_revert()),
-                SecurityBadge::Minter,
-            );
-        }
-    }
-    if let Some(admin_list) = admin_list {
-        for admin in admin_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64::encode(admin.to_bytes().unwrap_or_revert()),
-                SecurityBadge::Admin,
-            );
+    match wrapper::read_wrapper_mode() {
+        WrapperMode::Cspr => {
+            let purse: URef = runtime::get_named_arg(PURSE);
+            system::transfer_from_purse_to_purse(
+                purse,
+                wrapper::get_wrapper_purse(),
+                utils::u256_to_u512(amount),
+                None,
+            )
+            .unwrap_or_revert();
+            mint_tokens(owner, amount);
         }
+        WrapperMode::Vault => deposit_to_vault(owner, amount),
+        WrapperMode::Cep18 => wrap_tokens(owner, amount),
+        WrapperMode::Disabled => revert(Cep18Error::WrapperDisabled),
     }
-    events::record_event_dictionary(Event::Mint(Mint {
-        recipient: caller.into(),
-        amount: initial_supply,
-    }))
 }
 
-/// Admin EntryPoint to manipulate the security access granted to users.
-/// One user can only possess one access group badge.
-/// Change strength: None > Admin > Minter
-/// Change strength meaning by example: If user is added to both Minter and Admin they will be an
-/// Admin, also if a user is added to Admin and None then they will be removed from having rights.
-/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+/// Unwraps CSPR: burns `amount` tokens of the caller and sends the same amount of motes from the
+/// contract purse to the caller's account, or to the optional `purse` argument if given. Contracts
+/// calling this entry point must provide a purse. In the `Vault` wrapper mode, burns the shares
+/// corresponding to `amount` underlying tokens, rounded up, and sends those tokens to the caller.
+/// In the `Cep18` wrapper mode it is the same as `unwrap`.
 #[no_mangle]
-pub extern "C" fn change_security() {
-    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
-        revert(Cep18Error::MintBurnDisabled);
-    }
-    sec_check(vec![SecurityBadge::Admin]);
-    let admin_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
-    let minter_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
-    let none_list: Option<Vec<Key>> =
-        utils::get_optional_named_arg_with_user_errors(NONE_LIST, Cep18Error::InvalidNoneList);
+pub extern "C" fn withdraw() {
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
 
-    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
-    if let Some(minter_list) = minter_list {
-        for account_key in minter_list {
-            badge_map.insert(account_key, SecurityBadge::Minter);
-        }
-    }
-    if let Some(admin_list) = admin_list {
-        for account_key in admin_list {
-            badge_map.insert(account_key, SecurityBadge::Admin);
+    match wrapper::read_wrapper_mode() {
+        WrapperMode::Cspr => {
+            let purse: Option<URef> =
+                utils::get_optional_named_arg_with_user_errors(PURSE, Cep18Error::MissingPurse);
+            burn_tokens(owner, amount);
+            send_motes(wrapper::get_wrapper_purse(), owner, purse, amount);
         }
-    }
-    if let Some(none_list) = none_list {
-        for account_key in none_list {
-            badge_map.insert(account_key, SecurityBadge::None);
+        WrapperMode::Vault => {
+            let shares = wrapper::convert_to_shares(amount, Rounding::Up).unwrap_or_revert();
+            withdraw_from_vault(owner, amount, shares);
         }
+        WrapperMode::Cep18 => unwrap_tokens(owner, amount),
+        WrapperMode::Disabled => revert(Cep18Error::WrapperDisabled),
     }
-
-    let caller = get_immediate_caller_address().unwrap_or_revert();
-    badge_map.remove(&caller);
-
-    utils::change_sec_badge(&badge_map);
-    events::record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
-        admin: get_immediate_caller_address().unwrap_or_revert(),
-        sec_change_map: badge_map,
-    }));
 }
-pub fn upgrade(name: &str) {
-    let entry_points = generate_entry_points();
 
-    let contract_package_hash = runtime::get_key(&format!("{HASH_KEY_NAME_PREFIX}{name}"))
+/// Takes `amount` underlying tokens from the caller with `transfer_from` and mints the same amount
+/// of tokens to it. Only available in the `Cep18` wrapper mode.
+#[no_mangle]
+pub extern "C" fn wrap() {
+    check_cep18_wrapper_mode();
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    wrap_tokens(owner, amount);
+}
+
+/// Burns `amount` tokens of the caller and sends the same amount of underlying tokens to it. Only
+/// available in the `Cep18` wrapper mode.
+#[no_mangle]
+pub extern "C" fn unwrap() {
+    check_cep18_wrapper_mode();
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    unwrap_tokens(owner, amount);
+}
+
+fn check_cep18_wrapper_mode() {
+    if wrapper::read_wrapper_mode() != WrapperMode::Cep18 {
+        revert(Cep18Error::WrapperDisabled);
+    }
+}
+
+fn wrap_tokens(owner: Key, amount: U256) {
+    wrapper::pull_assets(owner, amount);
+    mint_tokens(owner, amount);
+}
+
+fn unwrap_tokens(owner: Key, amount: U256) {
+    burn_tokens(owner, amount);
+    wrapper::push_assets(owner, amount);
+    wrapper::check_backing().unwrap_or_revert();
+}
+
+/// Swaps `amount` legacy tokens of the caller for tokens of this contract at the configured ratio.
+/// The legacy tokens are pulled with `transfer_from` and stay locked under the contract's own
+/// package key, so the contract package must have been approved to spend them first.
+#[no_mangle]
+pub extern "C" fn migrate_from_legacy() {
+    legacy::check_migration_open().unwrap_or_revert();
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let legacy_amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    let amount = legacy::convert(legacy_amount).unwrap_or_revert();
+    legacy::lock_legacy_tokens(owner, legacy_amount);
+    mint_window::record_mint(amount).unwrap_or_revert();
+    mint_tokens(owner, amount);
+    events::record_event_dictionary(Event::LegacyMigrated(LegacyMigrated {
+        owner,
+        legacy_amount,
+        amount,
+    }));
+}
+
+/// Admin EntryPoint to close the migration from the legacy token for good.
+#[no_mangle]
+pub extern "C" fn close_legacy_migration() {
+    sec_check(vec![SecurityBadge::Admin]);
+    legacy::close_migration();
+}
+
+/// Council EntryPoint to propose calling the entry point `action` of this contract with the
+/// serialized runtime arguments `args`. Returns the id of the proposal, which counts as approved by
+/// the proposer. Only available in the admin council mode.
+#[no_mangle]
+pub extern "C" fn create_proposal() {
+    let proposer = get_immediate_caller_address().unwrap_or_revert();
+    let action: String = runtime::get_named_arg(ACTION);
+    let args: Bytes = runtime::get_named_arg(ARGS);
+
+    let proposal_id = council::create_proposal(proposer, action.clone(), args).unwrap_or_revert();
+    events::record_event_dictionary(Event::ProposalCreated(ProposalCreated {
+        proposal_id,
+        proposer,
+        action,
+    }));
+    runtime::ret(CLValue::from_t(proposal_id).unwrap_or_revert());
+}
+
+/// Council EntryPoint to approve the proposal `proposal_id`.
+#[no_mangle]
+pub extern "C" fn approve_proposal() {
+    let approver = get_immediate_caller_address().unwrap_or_revert();
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+
+    council::approve_proposal(proposal_id, approver).unwrap_or_revert();
+    events::record_event_dictionary(Event::ProposalApproved(ProposalApproved {
+        proposal_id,
+        approver,
+    }));
+}
+
+/// Council EntryPoint to execute the proposal `proposal_id` once enough current council members
+/// have approved it. The proposed entry point is called by the contract itself, which holds the
+/// Admin badge in the admin council mode.
+#[no_mangle]
+pub extern "C" fn execute_proposal() {
+    let executor = get_immediate_caller_address().unwrap_or_revert();
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+
+    council::execute_proposal(proposal_id, executor).unwrap_or_revert();
+    events::record_event_dictionary(Event::ProposalExecuted(ProposalExecuted {
+        proposal_id,
+        executor,
+    }));
+}
+
+/// Replaces the council and its threshold. Can only be called by the contract itself, i.e. by
+/// executing a proposal.
+#[no_mangle]
+pub extern "C" fn change_council() {
+    if get_immediate_caller_address().unwrap_or_revert() != utils::get_self_key() {
+        revert(Cep18Error::InsufficientRights);
+    }
+    let council: Vec<Key> = runtime::get_named_arg(COUNCIL);
+    let council_threshold: u32 = runtime::get_named_arg(COUNCIL_THRESHOLD);
+
+    council::write_council(council.clone(), council_threshold).unwrap_or_revert();
+    events::record_event_dictionary(Event::ChangeCouncil(ChangeCouncil {
+        council,
+        council_threshold,
+    }));
+}
+
+/// Executes the scheduled operation `operation_id` once its delay has passed. Can be called by
+/// anyone, as the operation was authorized when it was scheduled.
+#[no_mangle]
+pub extern "C" fn execute_scheduled() {
+    let operation_id: u64 = runtime::get_named_arg(OPERATION_ID);
+
+    timelock::execute(operation_id).unwrap_or_revert();
+    events::record_event_dictionary(Event::Executed(Executed {
+        operation_id,
+        executor: get_immediate_caller_address().unwrap_or_revert(),
+    }));
+}
+
+/// Admin EntryPoint to cancel the scheduled operation `operation_id`.
+#[no_mangle]
+pub extern "C" fn cancel_scheduled() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let operation_id: u64 = runtime::get_named_arg(OPERATION_ID);
+
+    timelock::cancel(operation_id).unwrap_or_revert();
+    events::record_event_dictionary(Event::Cancelled(Cancelled {
+        operation_id,
+        admin: get_immediate_caller_address().unwrap_or_revert(),
+    }));
+}
+
+/// Checks that the caller may call the timelocked admin entry point `action`, whose arguments are
+/// `params`, and returns `true` if the call is to be applied now. With the timelock enabled, the
+/// call of an admin is scheduled instead and applied when the scheduled operation is executed.
+fn authorize_timelocked(action: &str, params: &[(&str, CLType)]) -> bool {
+    if timelock::read_executing_scheduler().is_some() {
+        return true;
+    }
+    sec_check(vec![SecurityBadge::Admin]);
+    if !timelock::is_timelock_enabled() {
+        return true;
+    }
+    let scheduler = get_immediate_caller_address().unwrap_or_revert();
+    let (operation_id, eta) = timelock::schedule(scheduler, action, params).unwrap_or_revert();
+    events::record_event_dictionary(Event::Scheduled(Scheduled {
+        operation_id,
+        scheduler,
+        action: action.to_string(),
+        eta,
+    }));
+    false
+}
+
+/// Returns the admin on whose behalf a timelocked admin entry point is applied, which is the
+/// scheduler when a scheduled operation is executed.
+fn read_acting_admin() -> Key {
+    timelock::read_executing_scheduler()
+        .unwrap_or_else(|| get_immediate_caller_address().unwrap_or_revert())
+}
+
+/// Admin EntryPoint to start an airdrop of `total` tokens to the leaves of the Merkle tree with the
+/// given `root`. Outside the `MintBurn` mode the tokens are escrowed from the caller's balance.
+#[no_mangle]
+pub extern "C" fn set_merkle_root() {
+    sec_check(vec![SecurityBadge::Admin]);
+    let admin = get_immediate_caller_address().unwrap_or_revert();
+    let root: [u8; 32] = runtime::get_named_arg(ROOT);
+    let total: U256 = runtime::get_named_arg(TOTAL);
+
+    airdrop::set_merkle_root(admin, root, total).unwrap_or_revert();
+    events::record_event_dictionary(Event::MerkleRootSet(MerkleRootSet { admin, root, total }));
+}
+
+/// Claims the `amount` tokens airdropped to `account` at leaf `index`, given a Merkle `proof` of
+/// the leaf against the current root. Can be called by anyone; the tokens go to `account`.
+#[no_mangle]
+pub extern "C" fn claim() {
+    let index: u64 = runtime::get_named_arg(INDEX);
+    let account: Key = runtime::get_named_arg(ACCOUNT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let proof: Vec<[u8; 32]> = runtime::get_named_arg(PROOF);
+
+    airdrop::claim(index, account, amount, &proof).unwrap_or_revert();
+    if airdrop::is_minting() {
+        mint_window::record_mint(amount).unwrap_or_revert();
+        mint_tokens(account, amount);
+    } else {
+        balances::transfer_balance(utils::get_self_key(), account, amount).unwrap_or_revert();
+    }
+    events::record_event_dictionary(Event::AirdropClaimed(AirdropClaimed {
+        index,
+        account,
+        amount,
+    }));
+}
+
+/// Mints `amount` tokens to `recipient` as authorized off-chain by a voucher of a user with the
+/// `Minter` badge, signed by `signer_public_key`. Can be called by anyone. The mint counts towards
+/// the allowance of the signer and the mint window, just like a call to `mint` by the signer.
+#[no_mangle]
+pub extern "C" fn mint_with_voucher() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let nonce: u64 = runtime::get_named_arg(NONCE);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY);
+    let signer_public_key: PublicKey = runtime::get_named_arg(SIGNER_PUBLIC_KEY);
+    let signature: Bytes = runtime::get_named_arg(SIGNATURE);
+
+    let signer = vouchers::redeem(
+        recipient,
+        amount,
+        nonce,
+        expiry,
+        &signer_public_key,
+        &signature,
+    )
+    .unwrap_or_revert();
+    if minter_allowances::is_minter_allowances_enabled() {
+        let allowance =
+            minter_allowances::spend_minter_allowance(signer, amount).unwrap_or_revert();
+        events::record_event_dictionary(Event::DecreaseMinterAllowance(DecreaseMinterAllowance {
+            minter: signer,
+            allowance,
+            decr_by: amount,
+        }));
+    }
+    mint_window::record_mint(amount).unwrap_or_revert();
+
+    mint_tokens(recipient, amount);
+    events::record_event_dictionary(Event::VoucherRedeemed(VoucherRedeemed {
+        signer,
+        recipient,
+        amount,
+        nonce,
+    }));
+}
+
+/// Takes the underlying tokens `shares` vault shares are worth, rounded up, from the caller and
+/// mints the shares the received tokens are worth, which are fewer than `shares` if the underlying
+/// token delivers less than the transferred amount. Only available in the `Vault` wrapper mode.
+#[no_mangle]
+pub extern "C" fn mint_shares() {
+    check_vault_mode();
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let shares: U256 = runtime::get_named_arg(SHARES);
+
+    let assets = wrapper::convert_to_assets(shares, Rounding::Up).unwrap_or_revert();
+    deposit_to_vault(owner, assets);
+}
+
+/// Burns `shares` vault shares of the caller and sends the corresponding underlying tokens,
+/// rounded down, to the caller. Only available in the `Vault` wrapper mode.
+#[no_mangle]
+pub extern "C" fn redeem() {
+    check_vault_mode();
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let shares: U256 = runtime::get_named_arg(SHARES);
+
+    let assets = wrapper::convert_to_assets(shares, Rounding::Down).unwrap_or_revert();
+    withdraw_from_vault(owner, assets, shares);
+}
+
+#[no_mangle]
+pub extern "C" fn total_assets() {
+    check_vault_mode();
+    runtime::ret(CLValue::from_t(wrapper::total_assets()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn convert_to_shares() {
+    check_vault_mode();
+    let assets: U256 = runtime::get_named_arg(ASSETS);
+    let shares = wrapper::convert_to_shares(assets, Rounding::Down).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(shares).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn convert_to_assets() {
+    check_vault_mode();
+    let shares: U256 = runtime::get_named_arg(SHARES);
+    let assets = wrapper::convert_to_assets(shares, Rounding::Down).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(assets).unwrap_or_revert());
+}
+
+fn check_vault_mode() {
+    if wrapper::read_wrapper_mode() != WrapperMode::Vault {
+        revert(Cep18Error::WrapperDisabled);
+    }
+}
+
+/// Takes `assets` underlying tokens from `owner` and mints it the vault shares that the underlying
+/// tokens actually received are worth, rounded down.
+fn deposit_to_vault(owner: Key, assets: U256) {
+    if assets.is_zero() {
+        revert(Cep18Error::InvalidVaultAmount);
+    }
+    let (assets, shares) = wrapper::pull_vault_assets(owner, assets).unwrap_or_revert();
+    if shares.is_zero() {
+        revert(Cep18Error::InvalidVaultAmount);
+    }
+    mint_tokens(owner, shares);
+    events::record_event_dictionary(Event::VaultDeposit(VaultDeposit {
+        owner,
+        assets,
+        shares,
+    }));
+}
+
+/// Burns `shares` vault shares of `owner` and sends it `assets` underlying tokens.
+fn withdraw_from_vault(owner: Key, assets: U256, shares: U256) {
+    if assets.is_zero() || shares.is_zero() {
+        revert(Cep18Error::InvalidVaultAmount);
+    }
+    burn_tokens(owner, shares);
+    wrapper::push_assets(owner, assets);
+    events::record_event_dictionary(Event::VaultWithdraw(VaultWithdraw {
+        owner,
+        assets,
+        shares,
+    }));
+}
+
+/// Sends `amount` motes from `source` to `purse` if given, or else to the main purse of the
+/// account `owner`. Contracts have no main purse and must provide a purse.
+fn send_motes(source: URef, owner: Key, purse: Option<URef>, amount: U256) {
+    let motes = utils::u256_to_u512(amount);
+    match (purse, owner.into_account()) {
+        (Some(purse), _) => system::transfer_from_purse_to_purse(source, purse, motes, None),
+        (None, Some(account_hash)) => {
+            system::transfer_from_purse_to_account(source, account_hash, motes, None).map(|_| ())
+        }
+        (None, None) => revert(Cep18Error::MissingPurse),
+    }
+    .unwrap_or_revert();
+}
+
+/// Distributes `amount` of revenue to the token holders pro rata to their balances. In the `Cspr`
+/// dividends mode the motes are taken from the given `purse`. In the `Cep18` dividends mode the
+/// tokens are taken from the caller with `transfer_from`, so this contract package must have been
+/// approved to spend them first.
+#[no_mangle]
+pub extern "C" fn distribute() {
+    let distributor = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    match dividends::read_dividends_mode() {
+        DividendsMode::Disabled => revert(Cep18Error::DividendsDisabled),
+        DividendsMode::Cspr => {
+            let purse: URef = runtime::get_named_arg(PURSE);
+            system::transfer_from_purse_to_purse(
+                purse,
+                dividends::get_dividend_purse(),
+                utils::u256_to_u512(amount),
+                None,
+            )
+            .unwrap_or_revert();
+        }
+        DividendsMode::Cep18 => utils::call_token::<()>(
+            utils::read_from(DIVIDEND_TOKEN),
+            TRANSFER_FROM_ENTRY_POINT_NAME,
+            runtime_args! {
+                OWNER => distributor,
+                RECIPIENT => utils::get_self_key(),
+                AMOUNT => amount,
+            },
+        ),
+    }
+    dividends::distribute(amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::DividendDistributed(DividendDistributed {
+        distributor,
+        amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn withdrawable_dividend_of() {
+    if dividends::read_dividends_mode() == DividendsMode::Disabled {
+        revert(Cep18Error::DividendsDisabled);
+    }
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let dividend = dividends::withdrawable_dividend_of(address).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(dividend).unwrap_or_revert());
+}
+
+/// Pays out the dividend of the caller. In the `Cspr` dividends mode the motes are sent to the
+/// caller's account, or to the optional `purse` argument if given, which contracts calling this
+/// entry point must provide.
+#[no_mangle]
+pub extern "C" fn claim_dividend() {
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let mode = dividends::read_dividends_mode();
+    if mode == DividendsMode::Disabled {
+        revert(Cep18Error::DividendsDisabled);
+    }
+    let purse: Option<URef> =
+        utils::get_optional_named_arg_with_user_errors(PURSE, Cep18Error::MissingPurse);
+
+    let amount = dividends::claim_dividend(owner).unwrap_or_revert();
+    if mode == DividendsMode::Cspr {
+        send_motes(dividends::get_dividend_purse(), owner, purse, amount);
+    } else {
+        utils::call_token::<()>(
+            utils::read_from(DIVIDEND_TOKEN),
+            TRANSFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                RECIPIENT => owner,
+                AMOUNT => amount,
+            },
+        );
+    }
+    events::record_event_dictionary(Event::DividendClaimed(DividendClaimed { owner, amount }));
+}
+
+/// Puts `amount` tokens of the caller on hold in favor of `recipient` under `hold_id`. The held
+/// tokens cannot be spent by the caller until `notary` executes or releases the hold, or the
+/// caller releases it after `expiration`, given in block time. An expiration of zero means that the
+/// hold never expires.
+#[no_mangle]
+pub extern "C" fn hold() {
+    let payer = get_immediate_caller_address().unwrap_or_revert();
+    let hold_id: String = runtime::get_named_arg(HOLD_ID);
+    let hold = Hold {
+        payer,
+        recipient: runtime::get_named_arg(RECIPIENT),
+        notary: runtime::get_named_arg(NOTARY),
+        amount: runtime::get_named_arg(AMOUNT),
+        expiration: runtime::get_named_arg(EXPIRATION),
+        status: HoldStatus::Ordered,
+    };
+
+    holds::create_hold(&hold_id, &hold).unwrap_or_revert();
+    events::record_event_dictionary(Event::HoldCreated(HoldCreated {
+        hold_id,
+        payer,
+        recipient: hold.recipient,
+        notary: hold.notary,
+        amount: hold.amount,
+        expiration: hold.expiration,
+    }));
+}
+
+/// Notary EntryPoint to transfer the tokens on hold under `hold_id` to the recipient of the hold.
+#[no_mangle]
+pub extern "C" fn execute_hold() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let hold_id: String = runtime::get_named_arg(HOLD_ID);
+
+    let hold = holds::execute_hold(&hold_id, caller).unwrap_or_revert();
+    events::record_event_dictionary(Event::HoldExecuted(HoldExecuted {
+        hold_id,
+        payer: hold.payer,
+        recipient: hold.recipient,
+        notary: hold.notary,
+        amount: hold.amount,
+    }));
+}
+
+/// Releases the tokens on hold under `hold_id` back to the payer of the hold.
+#[no_mangle]
+pub extern "C" fn release_hold() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let hold_id: String = runtime::get_named_arg(HOLD_ID);
+
+    let hold = holds::release_hold(&hold_id, caller).unwrap_or_revert();
+    events::record_event_dictionary(Event::HoldReleased(HoldReleased {
+        hold_id,
+        payer: hold.payer,
+        released_by: caller,
+        amount: hold.amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn balance_on_hold() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let balance_on_hold = holds::read_balance_on_hold(address);
+    runtime::ret(CLValue::from_t(balance_on_hold).unwrap_or_revert());
+}
+
+/// Locks `amount` tokens of the caller in a stream to `recipient`, which become withdrawable
+/// linearly between `start_time` and `end_time`, given in block time. Returns the id of the stream.
+#[no_mangle]
+pub extern "C" fn create_stream() {
+    let sender = get_immediate_caller_address().unwrap_or_revert();
+    let stream = Stream {
+        sender,
+        recipient: runtime::get_named_arg(RECIPIENT),
+        amount: runtime::get_named_arg(AMOUNT),
+        start_time: runtime::get_named_arg(START_TIME),
+        end_time: runtime::get_named_arg(END_TIME),
+        withdrawn: U256::zero(),
+        cancelled: false,
+    };
+
+    let stream_id = streams::create_stream(&stream).unwrap_or_revert();
+    events::record_event_dictionary(Event::StreamCreated(StreamCreated {
+        stream_id,
+        sender,
+        recipient: stream.recipient,
+        amount: stream.amount,
+        start_time: stream.start_time,
+        end_time: stream.end_time,
+    }));
+    runtime::ret(CLValue::from_t(stream_id).unwrap_or_revert());
+}
+
+/// Recipient EntryPoint to withdraw all tokens of the stream `stream_id` vested so far.
+#[no_mangle]
+pub extern "C" fn withdraw_from_stream() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+
+    let (stream, amount) = streams::withdraw_from_stream(stream_id, caller).unwrap_or_revert();
+    events::record_event_dictionary(Event::StreamWithdrawn(StreamWithdrawn {
+        stream_id,
+        recipient: stream.recipient,
+        amount,
+    }));
+}
+
+/// Cancels the stream `stream_id`, paying the vested tokens to the recipient and refunding the
+/// rest to the sender. Can be called by the sender or the recipient of the stream.
+#[no_mangle]
+pub extern "C" fn cancel_stream() {
+    let caller = get_immediate_caller_address().unwrap_or_revert();
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+
+    let (stream, recipient_amount, sender_amount) =
+        streams::cancel_stream(stream_id, caller).unwrap_or_revert();
+    events::record_event_dictionary(Event::StreamCancelled(StreamCancelled {
+        stream_id,
+        sender: stream.sender,
+        recipient: stream.recipient,
+        cancelled_by: caller,
+        recipient_amount,
+        sender_amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn stream_balance() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let stream_balance = streams::read_stream_balance(stream_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(stream_balance).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to set the number of tokens `minter` may still mint. Admins are not limited
+/// by mint allowances. Only available when minter allowances are enabled.
+#[no_mangle]
+pub extern "C" fn configure_minter() {
+    if !minter_allowances::is_minter_allowances_enabled() {
+        revert(Cep18Error::MinterAllowancesDisabled);
+    }
+    if !authorize_timelocked(
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        &[(MINTER, Key::cl_type()), (ALLOWANCE, U256::cl_type())],
+    ) {
+        return;
+    }
+
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let allowance: U256 = runtime::get_named_arg(ALLOWANCE);
+
+    minter_allowances::write_minter_allowance(minter, allowance);
+    events::record_event_dictionary(Event::ConfigureMinter(ConfigureMinter {
+        admin: read_acting_admin(),
+        minter,
+        allowance,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn minter_allowance() {
+    if !minter_allowances::is_minter_allowances_enabled() {
+        revert(Cep18Error::MinterAllowancesDisabled);
+    }
+    let minter: Key = runtime::get_named_arg(MINTER);
+    let allowance = minter_allowances::read_minter_allowance(minter);
+    runtime::ret(CLValue::from_t(allowance).unwrap_or_revert());
+}
+
+/// Admin EntryPoint to adjust the protocol-wide mint limit: at most `mint_window_limit` tokens can
+/// be minted per window of `mint_window_seconds`. A window length of zero disables the limit.
+#[no_mangle]
+pub extern "C" fn change_mint_window() {
+    if !authorize_timelocked(
+        CHANGE_MINT_WINDOW_ENTRY_POINT_NAME,
+        &[
+            (MINT_WINDOW_SECONDS, u64::cl_type()),
+            (MINT_WINDOW_LIMIT, U256::cl_type()),
+        ],
+    ) {
+        return;
+    }
+    let mint_window_seconds: Option<u64> = utils::get_optional_named_arg_with_user_errors(
+        MINT_WINDOW_SECONDS,
+        Cep18Error::InvalidMintWindow,
+    );
+    let mint_window_limit: Option<U256> = utils::get_optional_named_arg_with_user_errors(
+        MINT_WINDOW_LIMIT,
+        Cep18Error::InvalidMintWindow,
+    );
+
+    if let Some(mint_window_seconds) = mint_window_seconds {
+        mint_window::write_mint_window_seconds(mint_window_seconds);
+    }
+    if let Some(mint_window_limit) = mint_window_limit {
+        mint_window::write_mint_window_limit(mint_window_limit);
+    }
+    events::record_event_dictionary(Event::ChangeMintWindow(ChangeMintWindow {
+        admin: read_acting_admin(),
+        mint_window_seconds: read_from(MINT_WINDOW_SECONDS),
+        mint_window_limit: read_from(MINT_WINDOW_LIMIT),
+    }));
+}
+
+/// Admin EntryPoint to set the daily transfer limit of accounts without an override. Only
+/// available in the `TransferLimits` mode.
+#[no_mangle]
+pub extern "C" fn set_default_transfer_limit() {
+    if !transfer_limits::is_transfer_limits_enabled() {
+        revert(Cep18Error::TransferLimitsDisabled);
+    }
+    if !authorize_timelocked(
+        SET_DEFAULT_TRANSFER_LIMIT_ENTRY_POINT_NAME,
+        &[(DAILY_TRANSFER_LIMIT, U256::cl_type())],
+    ) {
+        return;
+    }
+
+    let daily_transfer_limit: U256 = runtime::get_named_arg(DAILY_TRANSFER_LIMIT);
+    storage::write(utils::get_uref(DAILY_TRANSFER_LIMIT), daily_transfer_limit);
+    events::record_event_dictionary(Event::ChangeDefaultTransferLimit(
+        ChangeDefaultTransferLimit {
+            admin: read_acting_admin(),
+            daily_transfer_limit,
+        },
+    ));
+}
+
+/// Admin EntryPoint to override the daily transfer limit of `address`, or to remove its override
+/// when `daily_transfer_limit` is `None`. Only available in the `TransferLimits` mode.
+#[no_mangle]
+pub extern "C" fn set_transfer_limit() {
+    if !transfer_limits::is_transfer_limits_enabled() {
+        revert(Cep18Error::TransferLimitsDisabled);
+    }
+    if !authorize_timelocked(
+        SET_TRANSFER_LIMIT_ENTRY_POINT_NAME,
+        &[
+            (ADDRESS, Key::cl_type()),
+            (DAILY_TRANSFER_LIMIT, Option::<U256>::cl_type()),
+        ],
+    ) {
+        return;
+    }
+
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let daily_transfer_limit: Option<U256> = runtime::get_named_arg(DAILY_TRANSFER_LIMIT);
+    transfer_limits::write_limit_override(address, daily_transfer_limit);
+    events::record_event_dictionary(Event::SetTransferLimit(SetTransferLimit {
+        admin: read_acting_admin(),
+        address,
+        daily_transfer_limit,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn remaining_daily_limit() {
+    if !transfer_limits::is_transfer_limits_enabled() {
+        revert(Cep18Error::TransferLimitsDisabled);
+    }
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let remaining = transfer_limits::remaining_daily_limit(address);
+    runtime::ret(CLValue::from_t(remaining).unwrap_or_revert());
+}
+
+/// Mints `amount` tokens to `recipient` for a transfer locked on another chain and identified by
+/// `external_ref`. Each external reference can only be used once, so relayer retries cannot mint
+/// twice. Only available to Bridge users.
+#[no_mangle]
+pub extern "C" fn bridge_mint() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    sec_check(vec![SecurityBadge::Bridge]);
+
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let external_ref: String = runtime::get_named_arg(EXTERNAL_REF);
+
+    bridge::record_external_ref(&external_ref).unwrap_or_revert();
+    mint_window::record_mint(amount).unwrap_or_revert();
+    mint_tokens(recipient, amount);
+    events::record_event_dictionary(Event::BridgeMint(BridgeMint {
+        bridge: get_immediate_caller_address().unwrap_or_revert(),
+        recipient,
+        amount,
+        external_ref,
+    }));
+}
+
+/// Burns `amount` tokens of the caller to be released to `destination_address` on
+/// `destination_chain` by the bridge relayer.
+#[no_mangle]
+pub extern "C" fn bridge_burn() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    let owner = get_immediate_caller_address().unwrap_or_revert();
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let destination_chain: String = runtime::get_named_arg(DESTINATION_CHAIN);
+    let destination_address: String = runtime::get_named_arg(DESTINATION_ADDRESS);
+
+    burn_tokens(owner, amount);
+    events::record_event_dictionary(Event::BridgeBurn(BridgeBurn {
+        owner,
+        amount,
+        destination_chain,
+        destination_address,
+    }));
+}
+
+/// Initiates the contracts states. Only used by the installer call,
+/// later calls will cause it to revert.
+#[no_mangle]
+pub extern "C" fn init() {
+    if get_key(ALLOWANCES).is_some() {
+        revert(Cep18Error::AlreadyInitialized);
+    }
+    let package_hash = get_named_arg::<Key>(PACKAGE_HASH);
+    put_key(PACKAGE_HASH, package_hash);
+    storage::new_dictionary(ALLOWANCES).unwrap_or_revert();
+    storage::new_dictionary(PROCESSED_REFS).unwrap_or_revert();
+    storage::new_dictionary(HOLDS).unwrap_or_revert();
+    storage::new_dictionary(BALANCES_ON_HOLD).unwrap_or_revert();
+    storage::new_dictionary(STREAMS).unwrap_or_revert();
+    put_key(STREAM_COUNT, storage::new_uref(0u64).into());
+    storage::new_dictionary(AIRDROP_CLAIMED).unwrap_or_revert();
+    put_key(MERKLE_ROOT, storage::new_uref(None::<[u8; 32]>).into());
+    put_key(AIRDROP_REMAINING, storage::new_uref(U256::zero()).into());
+    put_key(AIRDROP_EPOCH, storage::new_uref(0u64).into());
+    storage::new_dictionary(REDEEMED_VOUCHERS).unwrap_or_revert();
+    storage::new_dictionary(AUTHORIZATION_STATES).unwrap_or_revert();
+    storage::new_dictionary(METADATA).unwrap_or_revert();
+    if holders::is_holder_registry_enabled() {
+        storage::new_dictionary(HOLDERS).unwrap_or_revert();
+        storage::new_dictionary(HOLDER_INDEXES).unwrap_or_revert();
+    }
+    let balances_uref = storage::new_dictionary(BALANCES).unwrap_or_revert();
+    let initial_supply = runtime::get_named_arg(TOTAL_SUPPLY);
+    let caller = get_caller();
+    write_balance_to(balances_uref, caller.into(), initial_supply);
+
+    // In the admin council mode the contract itself is the admin, acting on executed proposals.
+    let admin = if council::is_council_enabled() {
+        storage::new_dictionary(PROPOSALS).unwrap_or_revert();
+        put_key(PROPOSAL_COUNT, storage::new_uref(0u64).into());
+        package_hash
+    } else {
+        Key::from(get_caller())
+    };
+    let security_badges_dict = storage::new_dictionary(SECURITY_BADGES).unwrap_or_revert();
+    dictionary_put(
+        security_badges_dict,
+        &base64::encode(admin.to_bytes().unwrap_or_revert()),
+        SecurityBadge::Admin,
+    );
+
+    let admin_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
+    let minter_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+
+    init_events();
+
+    if timelock::is_timelock_enabled() {
+        storage::new_dictionary(SCHEDULED_OPERATIONS).unwrap_or_revert();
+    }
+    if fees::is_transfer_fee_enabled() {
+        storage::new_dictionary(FEE_EXEMPTIONS).unwrap_or_revert();
+    }
+    if minter_allowances::is_minter_allowances_enabled() {
+        storage::new_dictionary(MINTER_ALLOWANCES).unwrap_or_revert();
+    }
+    if max_balance::is_max_balance_enabled() {
+        storage::new_dictionary(MAX_BALANCE_EXEMPTIONS).unwrap_or_revert();
+    }
+    if transfer_limits::is_transfer_limits_enabled() {
+        storage::new_dictionary(TRANSFER_LIMIT_OVERRIDES).unwrap_or_revert();
+        storage::new_dictionary(DAILY_TRANSFERS).unwrap_or_revert();
+    }
+    if wrapper::read_wrapper_mode() == WrapperMode::Cspr {
+        put_key(WRAPPER_PURSE, system::create_purse().into());
+    }
+    let dividends_mode = dividends::read_dividends_mode();
+    if dividends_mode != DividendsMode::Disabled {
+        storage::new_dictionary(DIVIDEND_CORRECTIONS).unwrap_or_revert();
+    }
+    if dividends_mode == DividendsMode::Cspr {
+        put_key(DIVIDEND_PURSE, system::create_purse().into());
+    }
+
+    if let Some(minter_list) = minter_list {
+        for minter in minter_list {
+            dictionary_put(
+                security_badges_dict,
+                &base64::encode(minter.to_bytes().unwrap_or_revert()),
+                SecurityBadge::Minter,
+            );
+        }
+    }
+    if let Some(admin_list) = admin_list {
+        for admin in admin_list {
+            dictionary_put(
+                security_badges_dict,
+                &base64::encode(admin.to_bytes().unwrap_or_revert()),
+                SecurityBadge::Admin,
+            );
+        }
+    }
+    events::record_event_dictionary(Event::Mint(Mint {
+        recipient: caller.into(),
+        amount: initial_supply,
+    }))
+}
+
+/// Admin EntryPoint to manipulate the security access granted to users.
+/// One user can only possess one access group badge.
+/// Change strength: None > Admin > Minter > Rebaser > Bridge
+/// Change strength meaning by example: If user is added to both Minter and Admin they will be an
+/// Admin, also if a user is added to Admin and None then they will be removed from having rights.
+/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+#[no_mangle]
+pub extern "C" fn change_security() {
+    if 0 == read_from::<u8>(ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    if !authorize_timelocked(
+        CHANGE_SECURITY_ENTRY_POINT_NAME,
+        &[
+            (ADMIN_LIST, Vec::<Key>::cl_type()),
+            (MINTER_LIST, Vec::<Key>::cl_type()),
+            (NONE_LIST, Vec::<Key>::cl_type()),
+            (REBASER_LIST, Vec::<Key>::cl_type()),
+            (BRIDGE_LIST, Vec::<Key>::cl_type()),
+        ],
+    ) {
+        return;
+    }
+    let admin_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
+    let minter_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let none_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(NONE_LIST, Cep18Error::InvalidNoneList);
+    let rebaser_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        REBASER_LIST,
+        Cep18Error::InvalidRebaserList,
+    );
+
+    let bridge_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(BRIDGE_LIST, Cep18Error::InvalidBridgeList);
+
+    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    if let Some(bridge_list) = bridge_list {
+        for account_key in bridge_list {
+            badge_map.insert(account_key, SecurityBadge::Bridge);
+        }
+    }
+    if let Some(rebaser_list) = rebaser_list {
+        for account_key in rebaser_list {
+            badge_map.insert(account_key, SecurityBadge::Rebaser);
+        }
+    }
+    if let Some(minter_list) = minter_list {
+        for account_key in minter_list {
+            badge_map.insert(account_key, SecurityBadge::Minter);
+        }
+    }
+    if let Some(admin_list) = admin_list {
+        for account_key in admin_list {
+            badge_map.insert(account_key, SecurityBadge::Admin);
+        }
+    }
+    if let Some(none_list) = none_list {
+        for account_key in none_list {
+            badge_map.insert(account_key, SecurityBadge::None);
+        }
+    }
+
+    let caller = read_acting_admin();
+    badge_map.remove(&caller);
+
+    utils::change_sec_badge(&badge_map);
+    events::record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
+        admin: read_acting_admin(),
+        sec_change_map: badge_map,
+    }));
+}
+
+/// Admin EntryPoint to adjust the transfer fee rate and treasury, and to manage the accounts that
+/// are exempt from paying the fee. The rate is bounded by `MAX_TRANSFER_FEE_BPS`.
+/// If an account is present in both the exempt and non-exempt lists, it will not be exempt.
+#[no_mangle]
+pub extern "C" fn change_transfer_fee() {
+    if !fees::is_transfer_fee_enabled() {
+        revert(Cep18Error::TransferFeeDisabled);
+    }
+    if !authorize_timelocked(
+        CHANGE_TRANSFER_FEE_ENTRY_POINT_NAME,
+        &[
+            (TRANSFER_FEE_BPS, u32::cl_type()),
+            (TRANSFER_FEE_TREASURY, Key::cl_type()),
+            (EXEMPT_LIST, Vec::<Key>::cl_type()),
+            (NON_EXEMPT_LIST, Vec::<Key>::cl_type()),
+        ],
+    ) {
+        return;
+    }
+    let transfer_fee_bps: Option<u32> = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_FEE_BPS,
+        Cep18Error::InvalidTransferFee,
+    );
+    let treasury: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        TRANSFER_FEE_TREASURY,
+        Cep18Error::InvalidFeeTreasury,
+    );
+    let exempt_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(EXEMPT_LIST, Cep18Error::InvalidExemptList);
+    let non_exempt_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        NON_EXEMPT_LIST,
+        Cep18Error::InvalidNonExemptList,
+    );
+
+    if let Some(transfer_fee_bps) = transfer_fee_bps {
+        fees::write_transfer_fee_bps(transfer_fee_bps);
+    }
+    if let Some(treasury) = treasury {
+        storage::write(utils::get_uref(TRANSFER_FEE_TREASURY), treasury);
+    }
+
+    let mut exemptions: BTreeMap<Key, bool> = BTreeMap::new();
+    if let Some(exempt_list) = exempt_list {
+        for account_key in exempt_list {
+            exemptions.insert(account_key, true);
+        }
+    }
+    if let Some(non_exempt_list) = non_exempt_list {
+        for account_key in non_exempt_list {
+            exemptions.insert(account_key, false);
+        }
+    }
+    fees::change_fee_exemptions(&exemptions);
+
+    events::record_event_dictionary(Event::ChangeTransferFee(ChangeTransferFee {
+        admin: read_acting_admin(),
+        transfer_fee_bps: read_from::<u32>(TRANSFER_FEE_BPS),
+        treasury: read_from::<Key>(TRANSFER_FEE_TREASURY),
+        exemptions,
+    }));
+}
+
+/// Admin EntryPoint to adjust the maximum balance of non-exempt holders, and to manage the
+/// accounts that are exempt from it. If an account is present in both the exempt and non-exempt
+/// lists, it will not be exempt. Only available in the `MaxBalance` mode.
+#[no_mangle]
+pub extern "C" fn change_max_balance() {
+    if !max_balance::is_max_balance_enabled() {
+        revert(Cep18Error::MaxBalanceDisabled);
+    }
+    if !authorize_timelocked(
+        CHANGE_MAX_BALANCE_ENTRY_POINT_NAME,
+        &[
+            (MAX_BALANCE, U256::cl_type()),
+            (EXEMPT_LIST, Vec::<Key>::cl_type()),
+            (NON_EXEMPT_LIST, Vec::<Key>::cl_type()),
+        ],
+    ) {
+        return;
+    }
+    let new_max_balance: Option<U256> =
+        utils::get_optional_named_arg_with_user_errors(MAX_BALANCE, Cep18Error::InvalidMaxBalance);
+    let exempt_list: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(EXEMPT_LIST, Cep18Error::InvalidExemptList);
+    let non_exempt_list: Option<Vec<Key>> = utils::get_optional_named_arg_with_user_errors(
+        NON_EXEMPT_LIST,
+        Cep18Error::InvalidNonExemptList,
+    );
+
+    if let Some(new_max_balance) = new_max_balance {
+        storage::write(utils::get_uref(MAX_BALANCE), new_max_balance);
+    }
+
+    let mut exemptions: BTreeMap<Key, bool> = BTreeMap::new();
+    if let Some(exempt_list) = exempt_list {
+        for account_key in exempt_list {
+            exemptions.insert(account_key, true);
+        }
+    }
+    if let Some(non_exempt_list) = non_exempt_list {
+        for account_key in non_exempt_list {
+            exemptions.insert(account_key, false);
+        }
+    }
+    max_balance::change_max_balance_exemptions(&exemptions);
+
+    events::record_event_dictionary(Event::ChangeMaxBalance(ChangeMaxBalance {
+        admin: read_acting_admin(),
+        max_balance: read_from::<U256>(MAX_BALANCE),
+        exemptions,
+    }));
+}
+
+/// Rebaser EntryPoint to rebase the token to a new total supply. Balances of all holders are
+/// scaled proportionally by adjusting the global rebase index, so that the stored shares are worth
+/// `new_total_supply` tokens in total. Only available in the rebasing mode.
+#[no_mangle]
+pub extern "C" fn rebase() {
+    if !rebasing::is_rebasing_enabled() {
+        revert(Cep18Error::RebasingDisabled);
+    }
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Rebaser]);
+
+    let new_total_supply: U256 = runtime::get_named_arg(NEW_TOTAL_SUPPLY);
+    let total_shares = read_total_supply_from(get_total_supply_uref());
+    let rebase_index =
+        rebasing::compute_rebase_index(total_shares, new_total_supply).unwrap_or_revert();
+    rebasing::write_rebase_index(rebase_index);
+    wrapper::check_backing().unwrap_or_revert();
+
+    events::record_event_dictionary(Event::Rebase(Rebase {
+        rebaser: get_immediate_caller_address().unwrap_or_revert(),
+        total_supply: rebasing::read_total_supply_amount(),
+        rebase_index,
+    }));
+}
+
+pub fn upgrade(name: &str) {
+    let entry_points = generate_entry_points();
+
+    let contract_package_hash = runtime::get_key(&format!("{HASH_KEY_NAME_PREFIX}{name}"))
         .unwrap_or_revert()
         .into_hash()
         .map(ContractPackageHash::new)
@@ -409,6 +1807,99 @@ pub fn install_contract(name: &str) {
     )
     .unwrap_or(0);
 
+    let enable_transfer_fee: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_TRANSFER_FEE,
+        Cep18Error::InvalidTransferFeeFlag,
+    )
+    .unwrap_or(0);
+
+    let enable_rebasing: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_REBASING,
+        Cep18Error::InvalidRebasingFlag,
+    )
+    .unwrap_or(0);
+
+    let enable_minter_allowances: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_MINTER_ALLOWANCES,
+        Cep18Error::InvalidMinterAllowancesFlag,
+    )
+    .unwrap_or(0);
+    MinterAllowances::try_from(enable_minter_allowances).unwrap_or_revert();
+
+    let mint_window_seconds: u64 = utils::get_optional_named_arg_with_user_errors(
+        MINT_WINDOW_SECONDS,
+        Cep18Error::InvalidMintWindow,
+    )
+    .unwrap_or(0);
+    let mint_window_limit: U256 = utils::get_optional_named_arg_with_user_errors(
+        MINT_WINDOW_LIMIT,
+        Cep18Error::InvalidMintWindow,
+    )
+    .unwrap_or_default();
+
+    let enable_max_balance: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_MAX_BALANCE,
+        Cep18Error::InvalidMaxBalanceFlag,
+    )
+    .unwrap_or(0);
+
+    let enable_transfer_limits: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_TRANSFER_LIMITS,
+        Cep18Error::InvalidTransferLimitsFlag,
+    )
+    .unwrap_or(0);
+
+    let wrapper_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        WRAPPER_MODE,
+        Cep18Error::InvalidWrapperMode,
+    )
+    .unwrap_or(0);
+    WrapperMode::try_from(wrapper_mode).unwrap_or_revert();
+
+    let timelock_delay_seconds: u64 = utils::get_optional_named_arg_with_user_errors(
+        TIMELOCK_DELAY_SECONDS,
+        Cep18Error::InvalidTimelockDelay,
+    )
+    .unwrap_or(0);
+
+    let council: Option<Vec<Key>> =
+        utils::get_optional_named_arg_with_user_errors(COUNCIL, Cep18Error::InvalidCouncil);
+    // With a council the package key is the only admin, so every admin action needs its approval.
+    if council.is_some()
+        && admin_list
+            .as_ref()
+            .map_or(false, |admins| !admins.is_empty())
+    {
+        revert(Cep18Error::InvalidAdminList);
+    }
+
+    let legacy_token: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        LEGACY_TOKEN,
+        Cep18Error::InvalidLegacyToken,
+    );
+
+    // Wrapped tokens must be backed by deposits, so they cannot be created any other way.
+    if WrapperMode::try_from(wrapper_mode).unwrap_or_revert() != WrapperMode::Disabled
+        && (!total_supply.is_zero()
+            || enable_mint_burn != 0
+            || enable_rebasing != 0
+            || legacy_token.is_some())
+    {
+        revert(Cep18Error::InvalidWrapperSupply);
+    }
+
+    let dividends_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        DIVIDENDS_MODE,
+        Cep18Error::InvalidDividendsMode,
+    )
+    .unwrap_or(0);
+
+    let enable_holder_registry: u8 = utils::get_optional_named_arg_with_user_errors(
+        ENABLE_HOLDER_REGISTRY,
+        Cep18Error::InvalidHolderRegistryFlag,
+    )
+    .unwrap_or(0);
+
     let mut named_keys = NamedKeys::new();
     named_keys.insert(NAME.to_string(), storage::new_uref(name).into());
     named_keys.insert(SYMBOL.to_string(), storage::new_uref(symbol).into());
@@ -417,6 +1908,14 @@ pub fn install_contract(name: &str) {
         TOTAL_SUPPLY.to_string(),
         storage::new_uref(total_supply).into(),
     );
+    named_keys.insert(
+        TOTAL_MINTED.to_string(),
+        storage::new_uref(total_supply).into(),
+    );
+    named_keys.insert(
+        TOTAL_BURNED.to_string(),
+        storage::new_uref(U256::zero()).into(),
+    );
     named_keys.insert(
         EVENTS_MODE.to_string(),
         storage::new_uref(events_mode).into(),
@@ -425,6 +1924,191 @@ pub fn install_contract(name: &str) {
         ENABLE_MINT_BURN.to_string(),
         storage::new_uref(enable_mint_burn).into(),
     );
+    named_keys.insert(
+        ENABLE_TRANSFER_FEE.to_string(),
+        storage::new_uref(enable_transfer_fee).into(),
+    );
+    if TransferFee::try_from(enable_transfer_fee).unwrap_or_revert() == TransferFee::Enabled {
+        let transfer_fee_bps: u32 = utils::get_optional_named_arg_with_user_errors(
+            TRANSFER_FEE_BPS,
+            Cep18Error::InvalidTransferFee,
+        )
+        .unwrap_or(0);
+        fees::validate_transfer_fee_bps(transfer_fee_bps).unwrap_or_revert();
+        let treasury: Key = utils::get_optional_named_arg_with_user_errors(
+            TRANSFER_FEE_TREASURY,
+            Cep18Error::InvalidFeeTreasury,
+        )
+        .unwrap_or_revert_with(Cep18Error::InvalidFeeTreasury);
+        named_keys.insert(
+            TRANSFER_FEE_BPS.to_string(),
+            storage::new_uref(transfer_fee_bps).into(),
+        );
+        named_keys.insert(
+            TRANSFER_FEE_TREASURY.to_string(),
+            storage::new_uref(treasury).into(),
+        );
+    }
+    named_keys.insert(
+        ENABLE_REBASING.to_string(),
+        storage::new_uref(enable_rebasing).into(),
+    );
+    if Rebasing::try_from(enable_rebasing).unwrap_or_revert() == Rebasing::Enabled {
+        named_keys.insert(
+            REBASE_INDEX.to_string(),
+            storage::new_uref(U256::from(REBASE_INDEX_PRECISION)).into(),
+        );
+    }
+    named_keys.insert(
+        ENABLE_MINTER_ALLOWANCES.to_string(),
+        storage::new_uref(enable_minter_allowances).into(),
+    );
+    named_keys.insert(
+        ENABLE_MAX_BALANCE.to_string(),
+        storage::new_uref(enable_max_balance).into(),
+    );
+    if MaxBalance::try_from(enable_max_balance).unwrap_or_revert() == MaxBalance::Enabled {
+        let max_balance: U256 = utils::get_optional_named_arg_with_user_errors(
+            MAX_BALANCE,
+            Cep18Error::InvalidMaxBalance,
+        )
+        .unwrap_or_revert_with(Cep18Error::InvalidMaxBalance);
+        named_keys.insert(
+            MAX_BALANCE.to_string(),
+            storage::new_uref(max_balance).into(),
+        );
+    }
+    named_keys.insert(
+        ENABLE_TRANSFER_LIMITS.to_string(),
+        storage::new_uref(enable_transfer_limits).into(),
+    );
+    if TransferLimits::try_from(enable_transfer_limits).unwrap_or_revert()
+        == TransferLimits::Enabled
+    {
+        let daily_transfer_limit: U256 = utils::get_optional_named_arg_with_user_errors(
+            DAILY_TRANSFER_LIMIT,
+            Cep18Error::InvalidDailyTransferLimit,
+        )
+        .unwrap_or_revert_with(Cep18Error::InvalidDailyTransferLimit);
+        named_keys.insert(
+            DAILY_TRANSFER_LIMIT.to_string(),
+            storage::new_uref(daily_transfer_limit).into(),
+        );
+    }
+    named_keys.insert(
+        MINT_WINDOW_SECONDS.to_string(),
+        storage::new_uref(mint_window_seconds).into(),
+    );
+    named_keys.insert(
+        MINT_WINDOW_LIMIT.to_string(),
+        storage::new_uref(mint_window_limit).into(),
+    );
+    named_keys.insert(
+        MINT_WINDOW_MINTS.to_string(),
+        storage::new_uref(Vec::<(u64, U256)>::new()).into(),
+    );
+    named_keys.insert(
+        WRAPPER_MODE.to_string(),
+        storage::new_uref(wrapper_mode).into(),
+    );
+    if matches!(
+        WrapperMode::try_from(wrapper_mode).unwrap_or_revert(),
+        WrapperMode::Vault | WrapperMode::Cep18
+    ) {
+        let underlying_token: Key = utils::get_optional_named_arg_with_user_errors(
+            UNDERLYING_TOKEN,
+            Cep18Error::InvalidUnderlyingToken,
+        )
+        .unwrap_or_revert_with(Cep18Error::InvalidUnderlyingToken);
+        named_keys.insert(
+            UNDERLYING_TOKEN.to_string(),
+            storage::new_uref(underlying_token).into(),
+        );
+    }
+    named_keys.insert(
+        TIMELOCK_DELAY_SECONDS.to_string(),
+        storage::new_uref(timelock_delay_seconds).into(),
+    );
+    if timelock_delay_seconds > 0 {
+        named_keys.insert(
+            TIMELOCK_SCHEDULER.to_string(),
+            storage::new_uref(None::<Key>).into(),
+        );
+        named_keys.insert(OPERATION_COUNT.to_string(), storage::new_uref(0u64).into());
+    }
+    if let Some(council) = council {
+        let council_threshold: u32 = utils::get_optional_named_arg_with_user_errors(
+            COUNCIL_THRESHOLD,
+            Cep18Error::InvalidCouncilThreshold,
+        )
+        .unwrap_or_revert_with(Cep18Error::InvalidCouncilThreshold);
+        council::validate_council(&council, council_threshold).unwrap_or_revert();
+        named_keys.insert(COUNCIL.to_string(), storage::new_uref(council).into());
+        named_keys.insert(
+            COUNCIL_THRESHOLD.to_string(),
+            storage::new_uref(council_threshold).into(),
+        );
+    }
+    if let Some(legacy_token) = legacy_token {
+        let legacy_ratio_numerator: U256 = utils::get_optional_named_arg_with_user_errors(
+            LEGACY_RATIO_NUMERATOR,
+            Cep18Error::InvalidLegacyRatio,
+        )
+        .unwrap_or_else(U256::one);
+        let legacy_ratio_denominator: U256 = utils::get_optional_named_arg_with_user_errors(
+            LEGACY_RATIO_DENOMINATOR,
+            Cep18Error::InvalidLegacyRatio,
+        )
+        .unwrap_or_else(U256::one);
+        legacy::validate_ratio(legacy_ratio_numerator, legacy_ratio_denominator).unwrap_or_revert();
+        named_keys.insert(
+            LEGACY_TOKEN.to_string(),
+            storage::new_uref(legacy_token).into(),
+        );
+        named_keys.insert(
+            LEGACY_RATIO_NUMERATOR.to_string(),
+            storage::new_uref(legacy_ratio_numerator).into(),
+        );
+        named_keys.insert(
+            LEGACY_RATIO_DENOMINATOR.to_string(),
+            storage::new_uref(legacy_ratio_denominator).into(),
+        );
+        named_keys.insert(
+            LEGACY_MIGRATION_OPEN.to_string(),
+            storage::new_uref(true).into(),
+        );
+    }
+    named_keys.insert(
+        DIVIDENDS_MODE.to_string(),
+        storage::new_uref(dividends_mode).into(),
+    );
+    let dividends_mode = DividendsMode::try_from(dividends_mode).unwrap_or_revert();
+    if dividends_mode != DividendsMode::Disabled {
+        named_keys.insert(
+            DIVIDEND_PER_SHARE.to_string(),
+            storage::new_uref(U256::zero()).into(),
+        );
+    }
+    if dividends_mode == DividendsMode::Cep18 {
+        let dividend_token: Key = utils::get_optional_named_arg_with_user_errors(
+            DIVIDEND_TOKEN,
+            Cep18Error::InvalidDividendToken,
+        )
+        .unwrap_or_revert_with(Cep18Error::InvalidDividendToken);
+        named_keys.insert(
+            DIVIDEND_TOKEN.to_string(),
+            storage::new_uref(dividend_token).into(),
+        );
+    }
+    named_keys.insert(
+        ENABLE_HOLDER_REGISTRY.to_string(),
+        storage::new_uref(enable_holder_registry).into(),
+    );
+    if HolderRegistry::try_from(enable_holder_registry).unwrap_or_revert()
+        == HolderRegistry::Enabled
+    {
+        named_keys.insert(HOLDER_COUNT.to_string(), storage::new_uref(0u64).into());
+    }
     let entry_points = generate_entry_points();
 
     let hash_key_name = format!("{HASH_KEY_NAME_PREFIX}{name}");
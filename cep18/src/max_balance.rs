@@ -0,0 +1,57 @@
+//! Implementation of the optional maximum balance per holder.
+use alloc::collections::BTreeMap;
+
+use casper_contract::{
+    contract_api::storage::{dictionary_get, dictionary_put},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ENABLE_MAX_BALANCE, MAX_BALANCE, MAX_BALANCE_EXEMPTIONS},
+    error::Cep18Error,
+    modalities::MaxBalance,
+    rebasing,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Returns `true` if the maximum balance mode is enabled for this contract instance.
+pub(crate) fn is_max_balance_enabled() -> bool {
+    read_optional_from::<u8>(ENABLE_MAX_BALANCE).unwrap_or_default() == MaxBalance::Enabled as u8
+}
+
+fn is_max_balance_exempt(address: Key) -> bool {
+    dictionary_get::<bool>(
+        get_uref(MAX_BALANCE_EXEMPTIONS),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+/// Checks that `address` may hold a balance of `new_balance`, given in stored units, i.e. shares in
+/// the rebasing mode. Always succeeds if the maximum balance mode is disabled.
+pub(crate) fn check_max_balance(address: Key, new_balance: U256) -> Result<(), Cep18Error> {
+    if !is_max_balance_enabled()
+        || is_max_balance_exempt(address)
+        || address == utils::get_self_key()
+    {
+        return Ok(());
+    }
+    if rebasing::shares_to_amount(new_balance)? > read_from::<U256>(MAX_BALANCE) {
+        return Err(Cep18Error::MaxBalanceExceeded);
+    }
+    Ok(())
+}
+
+/// Writes the given exemptions into the `max_balance_exemptions` dictionary.
+pub(crate) fn change_max_balance_exemptions(exemptions: &BTreeMap<Key, bool>) {
+    let exemptions_uref = get_uref(MAX_BALANCE_EXEMPTIONS);
+    for (&address, &exempt) in exemptions {
+        dictionary_put(
+            exemptions_uref,
+            &utils::make_dictionary_item_key(address),
+            exempt,
+        );
+    }
+}
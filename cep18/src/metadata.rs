@@ -0,0 +1,80 @@
+//! Implementation of the updatable token metadata.
+//!
+//! The display `name` and `symbol` stay in their named keys, so that the `name` and `symbol` entry
+//! points keep working, while the optional extended fields are kept in the `metadata` dictionary,
+//! keyed by field name. The token name passed at installation, which the package key names are
+//! derived from, is not affected by updates.
+use alloc::{collections::BTreeMap, string::String};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{self, dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+use crate::{
+    constants::{
+        DESCRIPTION, ICON_URL, MAX_METADATA_FIELD_LENGTH, METADATA, NAME, SYMBOL, WEBSITE,
+    },
+    error::Cep18Error,
+    utils::{get_uref, read_from},
+};
+
+/// Extended metadata fields, which are optional.
+pub(crate) const EXTENDED_FIELDS: [&str; 3] = [ICON_URL, WEBSITE, DESCRIPTION];
+
+fn check_length(value: &str) -> Result<(), Cep18Error> {
+    if value.len() > MAX_METADATA_FIELD_LENGTH {
+        return Err(Cep18Error::InvalidMetadata);
+    }
+    Ok(())
+}
+
+/// Updates the display name or symbol under the named key `field`, which cannot be empty.
+pub(crate) fn write_display_field(field: &str, value: String) -> Result<(), Cep18Error> {
+    if value.is_empty() {
+        return Err(Cep18Error::InvalidMetadata);
+    }
+    check_length(&value)?;
+    storage::write(get_uref(field), value);
+    Ok(())
+}
+
+/// Updates the extended metadata `field`. An empty `value` clears the field.
+///
+/// Contracts upgraded from a version without extended metadata create the `metadata` dictionary
+/// on their first update.
+pub(crate) fn write_extended_field(field: &str, value: String) -> Result<(), Cep18Error> {
+    check_length(&value)?;
+    let metadata_uref = match runtime::get_key(METADATA) {
+        Some(_) => get_uref(METADATA),
+        None => storage::new_dictionary(METADATA).unwrap_or_revert(),
+    };
+    dictionary_put(metadata_uref, field, value);
+    Ok(())
+}
+
+/// Reads the extended metadata `field`, returning `None` if it is not set.
+///
+/// Contracts upgraded from a version without extended metadata have no fields set.
+pub(crate) fn read_extended_field(field: &str) -> Option<String> {
+    runtime::get_key(METADATA)?;
+    dictionary_get::<String>(get_uref(METADATA), field)
+        .unwrap_or_revert()
+        .filter(|value| !value.is_empty())
+}
+
+/// Returns the display name and symbol together with the extended metadata fields that are set.
+pub(crate) fn read_metadata() -> BTreeMap<String, String> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert(String::from(NAME), read_from::<String>(NAME));
+    metadata.insert(String::from(SYMBOL), read_from::<String>(SYMBOL));
+    for field in EXTENDED_FIELDS {
+        if let Some(value) = read_extended_field(field) {
+            metadata.insert(String::from(field), value);
+        }
+    }
+    metadata
+}
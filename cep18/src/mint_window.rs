@@ -0,0 +1,59 @@
+//! Implementation of the optional protocol-wide mint limit per rolling time window.
+//!
+//! The mints of the last `mint_window_seconds` are kept in `mint_window_mints` as pairs of block
+//! time and amount, oldest first. A mint is only allowed if the tokens minted during the window
+//! ending at the current block time, including the new mint, do not exceed `mint_window_limit`.
+//! At most [`MAX_MINT_WINDOW_ENTRIES`] mints are kept: beyond that the oldest mint is merged into
+//! the next one, so that it is counted for longer than necessary but never dropped early.
+use alloc::vec::Vec;
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::U256;
+
+use crate::{
+    constants::{
+        MAX_MINT_WINDOW_ENTRIES, MINT_WINDOW_LIMIT, MINT_WINDOW_MINTS, MINT_WINDOW_SECONDS,
+    },
+    error::Cep18Error,
+    utils::{get_uref, read_from, read_optional_from},
+};
+
+/// Adds `amount` to the tokens minted in the window ending at the current block time, failing if
+/// that would exceed the window limit. Does nothing if the mint window is disabled, i.e. its
+/// length is zero.
+pub(crate) fn record_mint(amount: U256) -> Result<(), Cep18Error> {
+    let window_seconds = read_optional_from::<u64>(MINT_WINDOW_SECONDS).unwrap_or_default();
+    if window_seconds == 0 {
+        return Ok(());
+    }
+    let now: u64 = runtime::get_blocktime().into();
+    let window_millis = window_seconds.saturating_mul(1_000);
+    let mut mints: Vec<(u64, U256)> = read_from(MINT_WINDOW_MINTS);
+    mints.retain(|(minted_at, _)| minted_at.saturating_add(window_millis) > now);
+
+    let minted = mints
+        .iter()
+        .try_fold(amount, |total, (_, minted)| total.checked_add(*minted))
+        .filter(|minted| *minted <= read_from(MINT_WINDOW_LIMIT));
+    if minted.is_none() {
+        return Err(Cep18Error::MintWindowLimitExceeded);
+    }
+
+    mints.push((now, amount));
+    if mints.len() > MAX_MINT_WINDOW_ENTRIES {
+        let (_, oldest) = mints.remove(0);
+        mints[0].1 += oldest;
+    }
+    storage::write(get_uref(MINT_WINDOW_MINTS), mints);
+    Ok(())
+}
+
+/// Writes the window length in seconds. A length of zero disables the mint window.
+pub(crate) fn write_mint_window_seconds(window_seconds: u64) {
+    storage::write(get_uref(MINT_WINDOW_SECONDS), window_seconds);
+}
+
+/// Writes the maximum number of tokens that can be minted per window.
+pub(crate) fn write_mint_window_limit(limit: U256) {
+    storage::write(get_uref(MINT_WINDOW_LIMIT), limit);
+}
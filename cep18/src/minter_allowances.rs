@@ -0,0 +1,47 @@
+//! Implementation of the optional per-minter mint allowances.
+use casper_contract::{
+    contract_api::storage::{dictionary_get, dictionary_put},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ENABLE_MINTER_ALLOWANCES, MINTER_ALLOWANCES},
+    error::Cep18Error,
+    modalities::MinterAllowances,
+    utils::{self, get_uref, read_optional_from},
+};
+
+/// Returns `true` if minters are limited by their mint allowance in this contract instance.
+pub(crate) fn is_minter_allowances_enabled() -> bool {
+    read_optional_from::<u8>(ENABLE_MINTER_ALLOWANCES).unwrap_or_default()
+        == MinterAllowances::Enabled as u8
+}
+
+/// Reads the number of tokens `minter` may still mint.
+pub(crate) fn read_minter_allowance(minter: Key) -> U256 {
+    dictionary_get(
+        get_uref(MINTER_ALLOWANCES),
+        &utils::make_dictionary_item_key(minter),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+/// Writes the number of tokens `minter` may still mint.
+pub(crate) fn write_minter_allowance(minter: Key, allowance: U256) {
+    dictionary_put(
+        get_uref(MINTER_ALLOWANCES),
+        &utils::make_dictionary_item_key(minter),
+        allowance,
+    )
+}
+
+/// Deducts `amount` from the mint allowance of `minter` and returns the remaining allowance.
+pub(crate) fn spend_minter_allowance(minter: Key, amount: U256) -> Result<U256, Cep18Error> {
+    let allowance = read_minter_allowance(minter)
+        .checked_sub(amount)
+        .ok_or(Cep18Error::MinterAllowanceExceeded)?;
+    write_minter_allowance(minter, allowance);
+    Ok(allowance)
+}
@@ -41,3 +41,169 @@ impl TryFrom<u8> for MintBurn {
         }
     }
 }
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransferFee {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for TransferFee {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferFee::Disabled),
+            1 => Ok(TransferFee::Enabled),
+            _ => Err(Cep18Error::InvalidTransferFeeFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Rebasing {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for Rebasing {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Rebasing::Disabled),
+            1 => Ok(Rebasing::Enabled),
+            _ => Err(Cep18Error::InvalidRebasingFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MinterAllowances {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for MinterAllowances {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MinterAllowances::Disabled),
+            1 => Ok(MinterAllowances::Enabled),
+            _ => Err(Cep18Error::InvalidMinterAllowancesFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MaxBalance {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for MaxBalance {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MaxBalance::Disabled),
+            1 => Ok(MaxBalance::Enabled),
+            _ => Err(Cep18Error::InvalidMaxBalanceFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransferLimits {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for TransferLimits {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferLimits::Disabled),
+            1 => Ok(TransferLimits::Enabled),
+            _ => Err(Cep18Error::InvalidTransferLimitsFlag),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum DividendsMode {
+    Disabled = 0,
+    Cspr = 1,
+    Cep18 = 2,
+}
+
+impl TryFrom<u8> for DividendsMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DividendsMode::Disabled),
+            1 => Ok(DividendsMode::Cspr),
+            2 => Ok(DividendsMode::Cep18),
+            _ => Err(Cep18Error::InvalidDividendsMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum WrapperMode {
+    Disabled = 0,
+    Cspr = 1,
+    Vault = 2,
+    Cep18 = 3,
+}
+
+impl TryFrom<u8> for WrapperMode {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WrapperMode::Disabled),
+            1 => Ok(WrapperMode::Cspr),
+            2 => Ok(WrapperMode::Vault),
+            3 => Ok(WrapperMode::Cep18),
+            _ => Err(Cep18Error::InvalidWrapperMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum HolderRegistry {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for HolderRegistry {
+    type Error = Cep18Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(HolderRegistry::Disabled),
+            1 => Ok(HolderRegistry::Enabled),
+            _ => Err(Cep18Error::InvalidHolderRegistryFlag),
+        }
+    }
+}
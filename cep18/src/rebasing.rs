@@ -0,0 +1,96 @@
+//! Implementation of the optional rebasing mode.
+//!
+//! When rebasing is enabled, the `balances` dictionary and the `total_supply` named key hold
+//! shares rather than tokens. The token amount of a number of shares is the shares times the
+//! global rebase index, which is adjusted by the `rebase` entry point.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::U256;
+
+use crate::{
+    constants::{ENABLE_REBASING, REBASE_INDEX, REBASE_INDEX_PRECISION, TOTAL_SUPPLY},
+    error::Cep18Error,
+    modalities::Rebasing,
+    utils::{get_uref, read_from, read_optional_from},
+};
+
+/// Direction in which a conversion from tokens to shares is rounded.
+pub(crate) enum Rounding {
+    /// Used when crediting shares, e.g. on mint.
+    Down,
+    /// Used when debiting shares, e.g. on transfer or burn, so that the recipient side of an
+    /// operation never receives less than the requested amount.
+    Up,
+}
+
+/// Returns `true` if the rebasing mode is enabled for this contract instance.
+pub(crate) fn is_rebasing_enabled() -> bool {
+    read_optional_from::<u8>(ENABLE_REBASING).unwrap_or_default() == Rebasing::Enabled as u8
+}
+
+fn read_rebase_index() -> U256 {
+    read_from::<U256>(REBASE_INDEX)
+}
+
+/// Writes a new rebase index.
+pub(crate) fn write_rebase_index(index: U256) {
+    storage::write(get_uref(REBASE_INDEX), index);
+}
+
+/// Converts a token amount into shares. Returns the amount unchanged if rebasing is disabled.
+pub(crate) fn amount_to_shares(amount: U256, rounding: Rounding) -> Result<U256, Cep18Error> {
+    if !is_rebasing_enabled() {
+        return Ok(amount);
+    }
+    let index = read_rebase_index();
+    let scaled = amount
+        .checked_mul(U256::from(REBASE_INDEX_PRECISION))
+        .ok_or(Cep18Error::Overflow)?;
+    let shares = match rounding {
+        Rounding::Down => scaled / index,
+        Rounding::Up => {
+            let (quotient, remainder) = scaled.div_mod(index);
+            if remainder.is_zero() {
+                quotient
+            } else {
+                quotient + 1
+            }
+        }
+    };
+    Ok(shares)
+}
+
+/// Converts shares into a token amount, rounding down. Returns the shares unchanged if rebasing is
+/// disabled.
+pub(crate) fn shares_to_amount(shares: U256) -> Result<U256, Cep18Error> {
+    if !is_rebasing_enabled() {
+        return Ok(shares);
+    }
+    let amount = shares
+        .checked_mul(read_rebase_index())
+        .ok_or(Cep18Error::Overflow)?
+        / U256::from(REBASE_INDEX_PRECISION);
+    Ok(amount)
+}
+
+/// Computes the rebase index for which `total_shares` are worth `new_total_supply` tokens.
+pub(crate) fn compute_rebase_index(
+    total_shares: U256,
+    new_total_supply: U256,
+) -> Result<U256, Cep18Error> {
+    if total_shares.is_zero() {
+        return Err(Cep18Error::InvalidRebase);
+    }
+    let index = new_total_supply
+        .checked_mul(U256::from(REBASE_INDEX_PRECISION))
+        .ok_or(Cep18Error::Overflow)?
+        / total_shares;
+    if index.is_zero() {
+        return Err(Cep18Error::InvalidRebase);
+    }
+    Ok(index)
+}
+
+/// Returns the token amount of the raw value stored under the `total_supply` named key.
+pub(crate) fn read_total_supply_amount() -> U256 {
+    shares_to_amount(read_from::<U256>(TOTAL_SUPPLY)).unwrap_or_revert()
+}
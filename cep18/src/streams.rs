@@ -0,0 +1,205 @@
+//! Implementation of streams, which pay out a locked amount linearly over time.
+//!
+//! The tokens of a stream are escrowed under the contract's own package key in `balances` when
+//! the stream is created. Between `start_time` and `end_time` they become withdrawable by the
+//! recipient in proportion to the elapsed block time. Each stream is kept in the `streams`
+//! dictionary under its sequential id.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{self, dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+use crate::{
+    balances::transfer_balance,
+    constants::{STREAMS, STREAM_COUNT},
+    error::Cep18Error,
+    rebasing,
+    utils::{self, get_uref, read_from},
+};
+
+/// A stream of `amount` tokens from `sender` to `recipient`, vesting linearly between
+/// `start_time` and `end_time`, given in block time.
+#[derive(Clone, Copy)]
+pub(crate) struct Stream {
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub withdrawn: U256,
+    pub cancelled: bool,
+}
+
+impl Stream {
+    /// Returns the number of tokens of the stream vested at the current block time.
+    fn vested_amount(&self) -> Result<U256, Cep18Error> {
+        let now = u64::from(runtime::get_blocktime());
+        if now <= self.start_time {
+            return Ok(U256::zero());
+        }
+        if now >= self.end_time {
+            return Ok(self.amount);
+        }
+        self.amount
+            .checked_mul(U256::from(now - self.start_time))
+            .map(|vested| vested / U256::from(self.end_time - self.start_time))
+            .ok_or(Cep18Error::Overflow)
+    }
+
+    /// Returns the number of tokens the recipient can withdraw at the current block time.
+    fn withdrawable_amount(&self) -> Result<U256, Cep18Error> {
+        if self.cancelled {
+            return Ok(U256::zero());
+        }
+        Ok(self.vested_amount()?.saturating_sub(self.withdrawn))
+    }
+}
+
+impl CLTyped for Stream {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Stream {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.start_time.to_bytes()?);
+        result.append(&mut self.end_time.to_bytes()?);
+        result.append(&mut self.withdrawn.to_bytes()?);
+        result.append(&mut self.cancelled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.recipient.serialized_length()
+            + self.amount.serialized_length()
+            + self.start_time.serialized_length()
+            + self.end_time.serialized_length()
+            + self.withdrawn.serialized_length()
+            + self.cancelled.serialized_length()
+    }
+}
+
+impl FromBytes for Stream {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, remainder) = Key::from_bytes(bytes)?;
+        let (recipient, remainder) = Key::from_bytes(remainder)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (start_time, remainder) = u64::from_bytes(remainder)?;
+        let (end_time, remainder) = u64::from_bytes(remainder)?;
+        let (withdrawn, remainder) = U256::from_bytes(remainder)?;
+        let (cancelled, remainder) = bool::from_bytes(remainder)?;
+        let stream = Stream {
+            sender,
+            recipient,
+            amount,
+            start_time,
+            end_time,
+            withdrawn,
+            cancelled,
+        };
+        Ok((stream, remainder))
+    }
+}
+
+fn read_stream(stream_id: u64) -> Result<Stream, Cep18Error> {
+    dictionary_get(get_uref(STREAMS), &stream_id.to_string())
+        .unwrap_or_revert()
+        .ok_or(Cep18Error::StreamNotFound)
+}
+
+fn write_stream(stream_id: u64, stream: &Stream) {
+    dictionary_put(get_uref(STREAMS), &stream_id.to_string(), *stream)
+}
+
+/// Reads the stream with the given id, failing if it has been cancelled.
+fn read_active_stream(stream_id: u64) -> Result<Stream, Cep18Error> {
+    let stream = read_stream(stream_id)?;
+    if stream.cancelled {
+        return Err(Cep18Error::StreamNotActive);
+    }
+    Ok(stream)
+}
+
+/// Escrows `stream.amount` tokens of `stream.sender` and stores the stream under the next id,
+/// which is returned.
+///
+/// Streams are not available in the rebasing mode, as the escrow is held in shares while the
+/// stream is denominated in tokens.
+pub(crate) fn create_stream(stream: &Stream) -> Result<u64, Cep18Error> {
+    if rebasing::is_rebasing_enabled() {
+        return Err(Cep18Error::StreamWithRebasing);
+    }
+    if stream.amount.is_zero()
+        || stream.end_time <= stream.start_time
+        || stream.sender == stream.recipient
+    {
+        return Err(Cep18Error::InvalidStream);
+    }
+    transfer_balance(stream.sender, utils::get_self_key(), stream.amount)?;
+
+    let stream_id: u64 = read_from(STREAM_COUNT);
+    write_stream(stream_id, stream);
+    storage::write(get_uref(STREAM_COUNT), stream_id + 1);
+    Ok(stream_id)
+}
+
+/// Returns the number of tokens the recipient of the stream can withdraw at the current block
+/// time.
+pub(crate) fn read_stream_balance(stream_id: u64) -> Result<U256, Cep18Error> {
+    read_stream(stream_id)?.withdrawable_amount()
+}
+
+/// Pays out the vested tokens of the stream not yet withdrawn to `caller`, which must be its
+/// recipient. Returns the stream and the amount withdrawn.
+pub(crate) fn withdraw_from_stream(
+    stream_id: u64,
+    caller: Key,
+) -> Result<(Stream, U256), Cep18Error> {
+    let mut stream = read_active_stream(stream_id)?;
+    if caller != stream.recipient {
+        return Err(Cep18Error::InsufficientRights);
+    }
+    let amount = stream.withdrawable_amount()?;
+    stream.withdrawn += amount;
+    write_stream(stream_id, &stream);
+    transfer_balance(utils::get_self_key(), stream.recipient, amount)?;
+    Ok((stream, amount))
+}
+
+/// Cancels the stream on behalf of `caller`, which must be its sender or recipient. The vested
+/// tokens not yet withdrawn are paid out to the recipient and the unvested ones are refunded to
+/// the sender. Returns the stream and both amounts.
+pub(crate) fn cancel_stream(
+    stream_id: u64,
+    caller: Key,
+) -> Result<(Stream, U256, U256), Cep18Error> {
+    let mut stream = read_active_stream(stream_id)?;
+    if caller != stream.sender && caller != stream.recipient {
+        return Err(Cep18Error::InsufficientRights);
+    }
+    let recipient_amount = stream.withdrawable_amount()?;
+    let sender_amount = stream.amount - stream.withdrawn - recipient_amount;
+    stream.withdrawn += recipient_amount;
+    stream.cancelled = true;
+    write_stream(stream_id, &stream);
+
+    let escrow = utils::get_self_key();
+    transfer_balance(escrow, stream.recipient, recipient_amount)?;
+    transfer_balance(escrow, stream.sender, sender_amount)?;
+    Ok((stream, recipient_amount, sender_amount))
+}
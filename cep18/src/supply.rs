@@ -0,0 +1,74 @@
+//! Implementation of the cumulative mint and burn counters and of the supply audit.
+//!
+//! `total_minted` and `total_burned` count the tokens ever minted, including the initial supply,
+//! and burned. Contracts upgraded from a version without the counters start counting from their
+//! first mint or burn after the upgrade.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::U256;
+
+use crate::{
+    balances::{get_balances_uref, read_balance_from},
+    constants::{TOTAL_BURNED, TOTAL_MINTED},
+    error::Cep18Error,
+    holders,
+    utils::{get_total_supply_uref, read_optional_from, read_total_supply_from},
+};
+
+fn add_to_counter(name: &str, amount: U256) -> Result<(), Cep18Error> {
+    match runtime::get_key(name) {
+        Some(key) => {
+            let uref = key.into_uref().unwrap_or_revert();
+            let counter: U256 = storage::read(uref).unwrap_or_revert().unwrap_or_default();
+            storage::write(
+                uref,
+                counter.checked_add(amount).ok_or(Cep18Error::Overflow)?,
+            );
+        }
+        None => runtime::put_key(name, storage::new_uref(amount).into()),
+    }
+    Ok(())
+}
+
+/// Adds `amount` to the tokens ever minted.
+pub(crate) fn record_mint(amount: U256) -> Result<(), Cep18Error> {
+    add_to_counter(TOTAL_MINTED, amount)
+}
+
+/// Adds `amount` to the tokens ever burned.
+pub(crate) fn record_burn(amount: U256) -> Result<(), Cep18Error> {
+    add_to_counter(TOTAL_BURNED, amount)
+}
+
+/// Returns the number of tokens ever minted.
+pub(crate) fn read_total_minted() -> U256 {
+    read_optional_from(TOTAL_MINTED).unwrap_or_default()
+}
+
+/// Returns the number of tokens ever burned.
+pub(crate) fn read_total_burned() -> U256 {
+    read_optional_from(TOTAL_BURNED).unwrap_or_default()
+}
+
+/// Checks that the balances of all holders add up to the total supply, both in stored units, i.e.
+/// shares in the rebasing mode. Requires the holder registry, and its cost grows with the number
+/// of holders.
+pub(crate) fn audit() -> Result<(), Cep18Error> {
+    if !holders::is_holder_registry_enabled() {
+        return Err(Cep18Error::HolderRegistryDisabled);
+    }
+    let balances_uref = get_balances_uref();
+    let holders = holders::read_holders(0, holders::read_holder_count());
+    let mut sum = U256::zero();
+    for holder in holders {
+        sum = sum
+            .checked_add(read_balance_from(balances_uref, holder))
+            .ok_or(Cep18Error::Overflow)?;
+    }
+    if sum != read_total_supply_from(get_total_supply_uref()) {
+        return Err(Cep18Error::SupplyMismatch);
+    }
+    Ok(())
+}
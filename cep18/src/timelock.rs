@@ -0,0 +1,200 @@
+//! Implementation of the timelock, which delays privileged operations so that holders get notice
+//! before supply or role changes take effect.
+//!
+//! When `timelock_delay_seconds` is set, calls to timelocked admin entry points are not applied
+//! but stored in the `scheduled_operations` dictionary, together with their runtime arguments and
+//! the earliest block time at which they can be executed. Once that time has passed anyone can
+//! execute the operation, which calls the entry point again from the contract itself. While it
+//! runs, the admin who scheduled it is kept under `timelock_scheduler`, so that the entry point
+//! skips the security check already made when scheduling and acts on behalf of that admin.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{self, dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue, ContractPackageHash, Key, RuntimeArgs,
+};
+
+use crate::{
+    constants::{
+        OPERATION_COUNT, SCHEDULED_OPERATIONS, TIMELOCK_DELAY_SECONDS, TIMELOCK_SCHEDULER,
+    },
+    error::Cep18Error,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// A call to the entry point `action` of this contract with the serialized runtime arguments
+/// `args`, which can be executed from the block time `eta` on.
+#[derive(Clone)]
+pub(crate) struct Operation {
+    pub scheduler: Key,
+    pub action: String,
+    pub args: Bytes,
+    pub eta: u64,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+impl CLTyped for Operation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Operation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.scheduler.to_bytes()?);
+        result.append(&mut self.action.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        result.append(&mut self.eta.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        result.append(&mut self.cancelled.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.scheduler.serialized_length()
+            + self.action.serialized_length()
+            + self.args.serialized_length()
+            + self.eta.serialized_length()
+            + self.executed.serialized_length()
+            + self.cancelled.serialized_length()
+    }
+}
+
+impl FromBytes for Operation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (scheduler, remainder) = Key::from_bytes(bytes)?;
+        let (action, remainder) = String::from_bytes(remainder)?;
+        let (args, remainder) = Bytes::from_bytes(remainder)?;
+        let (eta, remainder) = u64::from_bytes(remainder)?;
+        let (executed, remainder) = bool::from_bytes(remainder)?;
+        let (cancelled, remainder) = bool::from_bytes(remainder)?;
+        let operation = Operation {
+            scheduler,
+            action,
+            args,
+            eta,
+            executed,
+            cancelled,
+        };
+        Ok((operation, remainder))
+    }
+}
+
+/// Returns `true` if the timelock is enabled for this contract instance.
+pub(crate) fn is_timelock_enabled() -> bool {
+    read_optional_from::<u64>(TIMELOCK_DELAY_SECONDS).unwrap_or_default() > 0
+}
+
+/// Returns the admin who scheduled the operation being executed, if the current call is the
+/// execution of a scheduled operation.
+pub(crate) fn read_executing_scheduler() -> Option<Key> {
+    let scheduler = read_optional_from::<Option<Key>>(TIMELOCK_SCHEDULER).flatten()?;
+    let caller = utils::get_immediate_caller_address().ok()?;
+    (caller == utils::get_self_key()).then_some(scheduler)
+}
+
+/// Captures the named arguments `params` of the current call that were passed, with their types.
+fn capture_args(params: &[(&str, CLType)]) -> Result<Bytes, Cep18Error> {
+    let mut args = RuntimeArgs::new();
+    for (name, cl_type) in params {
+        if let Some(bytes) = utils::get_named_arg_bytes(name) {
+            args.insert_cl_value(*name, CLValue::from_components(cl_type.clone(), bytes));
+        }
+    }
+    args.to_bytes()
+        .map(Bytes::from)
+        .map_err(|_| Cep18Error::FailedToGetArgBytes)
+}
+
+fn read_operation(operation_id: u64) -> Result<Operation, Cep18Error> {
+    dictionary_get(get_uref(SCHEDULED_OPERATIONS), &operation_id.to_string())
+        .unwrap_or_revert()
+        .ok_or(Cep18Error::OperationNotFound)
+}
+
+fn write_operation(operation_id: u64, operation: Operation) {
+    dictionary_put(
+        get_uref(SCHEDULED_OPERATIONS),
+        &operation_id.to_string(),
+        operation,
+    )
+}
+
+/// Reads a scheduled operation, checking that it has been neither executed nor cancelled.
+fn read_pending_operation(operation_id: u64) -> Result<Operation, Cep18Error> {
+    if !is_timelock_enabled() {
+        return Err(Cep18Error::TimelockDisabled);
+    }
+    let operation = read_operation(operation_id)?;
+    if operation.executed || operation.cancelled {
+        return Err(Cep18Error::OperationNotPending);
+    }
+    Ok(operation)
+}
+
+/// Schedules the current call to `action` by `scheduler`, with the arguments `params`, and returns
+/// the id of the operation and the block time from which it can be executed.
+pub(crate) fn schedule(
+    scheduler: Key,
+    action: &str,
+    params: &[(&str, CLType)],
+) -> Result<(u64, u64), Cep18Error> {
+    let delay_millis = read_from::<u64>(TIMELOCK_DELAY_SECONDS).saturating_mul(1_000);
+    let eta = u64::from(runtime::get_blocktime()).saturating_add(delay_millis);
+    let operation_id: u64 = read_from(OPERATION_COUNT);
+    write_operation(
+        operation_id,
+        Operation {
+            scheduler,
+            action: action.to_string(),
+            args: capture_args(params)?,
+            eta,
+            executed: false,
+            cancelled: false,
+        },
+    );
+    storage::write(get_uref(OPERATION_COUNT), operation_id + 1);
+    Ok((operation_id, eta))
+}
+
+/// Executes the operation `operation_id` by calling its entry point from the contract itself.
+pub(crate) fn execute(operation_id: u64) -> Result<(), Cep18Error> {
+    let mut operation = read_pending_operation(operation_id)?;
+    if u64::from(runtime::get_blocktime()) < operation.eta {
+        return Err(Cep18Error::OperationNotReady);
+    }
+    operation.executed = true;
+    write_operation(operation_id, operation.clone());
+
+    let args: RuntimeArgs =
+        bytesrepr::deserialize(operation.args.to_vec()).map_err(|_| Cep18Error::InvalidContext)?;
+    let package_hash = utils::get_self_key()
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(Cep18Error::InvalidContext)?;
+    let scheduler_uref = get_uref(TIMELOCK_SCHEDULER);
+    storage::write(scheduler_uref, Some(operation.scheduler));
+    runtime::call_versioned_contract::<()>(package_hash, None, &operation.action, args);
+    storage::write(scheduler_uref, None::<Key>);
+    Ok(())
+}
+
+/// Cancels the operation `operation_id`.
+pub(crate) fn cancel(operation_id: u64) -> Result<(), Cep18Error> {
+    let mut operation = read_pending_operation(operation_id)?;
+    operation.cancelled = true;
+    write_operation(operation_id, operation);
+    Ok(())
+}
@@ -0,0 +1,208 @@
+//! Implementation of the `token_info` view, which returns the metadata and configuration of the
+//! token in a single call.
+//!
+//! The layout of [`TokenInfo`] is versioned by [`TOKEN_INFO_SCHEMA_VERSION`], its first field,
+//! which is increased whenever fields are appended, so that clients know how to deserialize it.
+//! [`TokenInfo`] is serialized as [`TokenInfoLayout`], nested tuples whose bytes are the fields in
+//! order, so that the entry point has a structured CL type.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+use crate::{
+    constants::{
+        DECIMALS, DESCRIPTION, DIVIDENDS_MODE, ENABLE_HOLDER_REGISTRY, ENABLE_MAX_BALANCE,
+        ENABLE_MINTER_ALLOWANCES, ENABLE_MINT_BURN, ENABLE_REBASING, ENABLE_TRANSFER_FEE,
+        ENABLE_TRANSFER_LIMITS, EVENTS_MODE, ICON_URL, LEGACY_TOKEN, MINT_WINDOW_SECONDS, NAME,
+        SYMBOL, TOKEN_INFO_SCHEMA_VERSION, WEBSITE, WRAPPER_MODE,
+    },
+    council, metadata, rebasing, timelock,
+    utils::{read_from, read_optional_from},
+};
+
+/// The CL type and serialization of [`TokenInfo`]. CL tuples have at most three elements, so the
+/// fields are grouped into nested tuples.
+type TokenInfoLayout = (
+    u32,
+    (
+        (String, String, u8),
+        U256,
+        (Option<String>, Option<String>, Option<String>),
+    ),
+    (
+        ((u8, u8, u8), (u8, u8, u8), (u8, u8, u8)),
+        (u8, String),
+        ((u8, u8), (u8, u8)),
+    ),
+);
+
+/// Metadata, modalities and versions of the token.
+pub(crate) struct TokenInfo {
+    pub schema_version: u32,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: U256,
+    pub icon_url: Option<String>,
+    pub website: Option<String>,
+    pub description: Option<String>,
+    pub events_mode: u8,
+    pub enable_mint_burn: u8,
+    pub enable_transfer_fee: u8,
+    pub enable_rebasing: u8,
+    pub enable_minter_allowances: u8,
+    pub enable_max_balance: u8,
+    pub enable_transfer_limits: u8,
+    pub wrapper_mode: u8,
+    pub dividends_mode: u8,
+    pub enable_holder_registry: u8,
+    pub contract_version: String,
+    pub enable_timelock: u8,
+    pub enable_council: u8,
+    pub enable_legacy_migration: u8,
+    pub enable_mint_window: u8,
+}
+
+impl TokenInfo {
+    fn to_layout(&self) -> TokenInfoLayout {
+        (
+            self.schema_version,
+            (
+                (self.name.clone(), self.symbol.clone(), self.decimals),
+                self.total_supply,
+                (
+                    self.icon_url.clone(),
+                    self.website.clone(),
+                    self.description.clone(),
+                ),
+            ),
+            (
+                (
+                    (
+                        self.events_mode,
+                        self.enable_mint_burn,
+                        self.enable_transfer_fee,
+                    ),
+                    (
+                        self.enable_rebasing,
+                        self.enable_minter_allowances,
+                        self.enable_max_balance,
+                    ),
+                    (
+                        self.enable_transfer_limits,
+                        self.wrapper_mode,
+                        self.dividends_mode,
+                    ),
+                ),
+                (self.enable_holder_registry, self.contract_version.clone()),
+                (
+                    (self.enable_timelock, self.enable_council),
+                    (self.enable_legacy_migration, self.enable_mint_window),
+                ),
+            ),
+        )
+    }
+}
+
+impl CLTyped for TokenInfo {
+    fn cl_type() -> CLType {
+        TokenInfoLayout::cl_type()
+    }
+}
+
+impl ToBytes for TokenInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.to_layout().to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.to_layout().serialized_length()
+    }
+}
+
+impl FromBytes for TokenInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (
+            (
+                schema_version,
+                ((name, symbol, decimals), total_supply, (icon_url, website, description)),
+                (
+                    (
+                        (events_mode, enable_mint_burn, enable_transfer_fee),
+                        (enable_rebasing, enable_minter_allowances, enable_max_balance),
+                        (enable_transfer_limits, wrapper_mode, dividends_mode),
+                    ),
+                    (enable_holder_registry, contract_version),
+                    (
+                        (enable_timelock, enable_council),
+                        (enable_legacy_migration, enable_mint_window),
+                    ),
+                ),
+            ),
+            remainder,
+        ) = TokenInfoLayout::from_bytes(bytes)?;
+        let token_info = TokenInfo {
+            schema_version,
+            name,
+            symbol,
+            decimals,
+            total_supply,
+            icon_url,
+            website,
+            description,
+            events_mode,
+            enable_mint_burn,
+            enable_transfer_fee,
+            enable_rebasing,
+            enable_minter_allowances,
+            enable_max_balance,
+            enable_transfer_limits,
+            wrapper_mode,
+            dividends_mode,
+            enable_holder_registry,
+            contract_version,
+            enable_timelock,
+            enable_council,
+            enable_legacy_migration,
+            enable_mint_window,
+        };
+        Ok((token_info, remainder))
+    }
+}
+
+/// Reads the current [`TokenInfo`]. Modalities introduced after the initial release default to
+/// disabled in contracts upgraded from an older version.
+pub(crate) fn read_token_info() -> TokenInfo {
+    TokenInfo {
+        schema_version: TOKEN_INFO_SCHEMA_VERSION,
+        name: read_from(NAME),
+        symbol: read_from(SYMBOL),
+        decimals: read_from(DECIMALS),
+        total_supply: rebasing::read_total_supply_amount(),
+        icon_url: metadata::read_extended_field(ICON_URL),
+        website: metadata::read_extended_field(WEBSITE),
+        description: metadata::read_extended_field(DESCRIPTION),
+        events_mode: read_from(EVENTS_MODE),
+        enable_mint_burn: read_from(ENABLE_MINT_BURN),
+        enable_transfer_fee: read_optional_from(ENABLE_TRANSFER_FEE).unwrap_or_default(),
+        enable_rebasing: read_optional_from(ENABLE_REBASING).unwrap_or_default(),
+        enable_minter_allowances: read_optional_from(ENABLE_MINTER_ALLOWANCES).unwrap_or_default(),
+        enable_max_balance: read_optional_from(ENABLE_MAX_BALANCE).unwrap_or_default(),
+        enable_transfer_limits: read_optional_from(ENABLE_TRANSFER_LIMITS).unwrap_or_default(),
+        wrapper_mode: read_optional_from(WRAPPER_MODE).unwrap_or_default(),
+        dividends_mode: read_optional_from(DIVIDENDS_MODE).unwrap_or_default(),
+        enable_holder_registry: read_optional_from(ENABLE_HOLDER_REGISTRY).unwrap_or_default(),
+        contract_version: env!("CARGO_PKG_VERSION").to_string(),
+        enable_timelock: timelock::is_timelock_enabled() as u8,
+        enable_council: council::is_council_enabled() as u8,
+        enable_legacy_migration: read_optional_from::<Key>(LEGACY_TOKEN).is_some() as u8,
+        enable_mint_window: (read_optional_from::<u64>(MINT_WINDOW_SECONDS).unwrap_or_default() > 0)
+            as u8,
+    }
+}
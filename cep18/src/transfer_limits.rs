@@ -0,0 +1,93 @@
+//! Implementation of the optional per-account daily transfer limits.
+//!
+//! Every account can send at most its daily limit per day, where days are counted in block time
+//! since the Unix epoch. The limit is the default `daily_transfer_limit` unless an Admin user has
+//! set an override for the account in the `transfer_limit_overrides` dictionary.
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{
+        DAILY_TRANSFERS, DAILY_TRANSFER_LIMIT, ENABLE_TRANSFER_LIMITS, TRANSFER_LIMIT_OVERRIDES,
+    },
+    error::Cep18Error,
+    modalities::TransferLimits,
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Length of a day in block time, i.e. in milliseconds.
+const DAY_MILLIS: u64 = 86_400_000;
+
+/// Returns `true` if the transfer limits mode is enabled for this contract instance.
+pub(crate) fn is_transfer_limits_enabled() -> bool {
+    read_optional_from::<u8>(ENABLE_TRANSFER_LIMITS).unwrap_or_default()
+        == TransferLimits::Enabled as u8
+}
+
+fn current_day() -> u64 {
+    u64::from(runtime::get_blocktime()) / DAY_MILLIS
+}
+
+/// Reads the daily limit of `address`, which is its override if set or the default limit.
+fn read_daily_limit(address: Key) -> U256 {
+    dictionary_get::<Option<U256>>(
+        get_uref(TRANSFER_LIMIT_OVERRIDES),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .flatten()
+    .unwrap_or_else(|| read_from(DAILY_TRANSFER_LIMIT))
+}
+
+/// Reads the number of tokens `address` has sent today.
+fn read_sent_today(address: Key) -> U256 {
+    let (day, sent): (u64, U256) = dictionary_get(
+        get_uref(DAILY_TRANSFERS),
+        &utils::make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default();
+    if day == current_day() {
+        sent
+    } else {
+        U256::zero()
+    }
+}
+
+/// Returns the number of tokens `address` can still send today.
+pub(crate) fn remaining_daily_limit(address: Key) -> U256 {
+    read_daily_limit(address).saturating_sub(read_sent_today(address))
+}
+
+/// Adds `amount` to the tokens `sender` has sent today, failing if that would exceed its daily
+/// limit. Does nothing if the transfer limits mode is disabled.
+pub(crate) fn record_transfer(sender: Key, amount: U256) -> Result<(), Cep18Error> {
+    if !is_transfer_limits_enabled() || sender == utils::get_self_key() {
+        return Ok(());
+    }
+    let sent = read_sent_today(sender)
+        .checked_add(amount)
+        .filter(|sent| *sent <= read_daily_limit(sender))
+        .ok_or(Cep18Error::DailyTransferLimitExceeded)?;
+    dictionary_put(
+        get_uref(DAILY_TRANSFERS),
+        &utils::make_dictionary_item_key(sender),
+        (current_day(), sent),
+    );
+    Ok(())
+}
+
+/// Sets or, if `daily_limit` is `None`, removes the daily limit override of `address`.
+pub(crate) fn write_limit_override(address: Key, daily_limit: Option<U256>) {
+    dictionary_put(
+        get_uref(TRANSFER_LIMIT_OVERRIDES),
+        &utils::make_dictionary_item_key(address),
+        daily_limit,
+    )
+}
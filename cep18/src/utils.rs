@@ -1,7 +1,7 @@
 //! Implementation details.
 use core::convert::TryInto;
 
-use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 use casper_contract::{
     contract_api::{
         self,
@@ -14,12 +14,14 @@ use casper_contract::{
 use casper_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
+    crypto,
     system::CallStackElement,
-    ApiError, CLTyped, Key, URef, U256,
+    ApiError, CLTyped, ContractPackageHash, Key, PublicKey, RuntimeArgs, Signature, URef, U256,
+    U512,
 };
 
 use crate::{
-    constants::{SECURITY_BADGES, TOTAL_SUPPLY},
+    constants::{PACKAGE_HASH, SECURITY_BADGES, TOTAL_SUPPLY},
     error::Cep18Error,
 };
 
@@ -41,6 +43,46 @@ where
     value
 }
 
+/// Returns the contract's own package key, under which escrowed tokens are held in `balances`.
+pub(crate) fn get_self_key() -> Key {
+    runtime::get_key(PACKAGE_HASH)
+        .ok_or(ApiError::MissingKey)
+        .unwrap_or_revert()
+}
+
+/// Reads value from a named key, returning `None` if the named key does not exist.
+///
+/// Used for settings introduced after the initial release, which are missing in contracts upgraded
+/// from an older version.
+pub(crate) fn read_optional_from<T>(name: &str) -> Option<T>
+where
+    T: FromBytes + CLTyped,
+{
+    let key = runtime::get_key(name)?;
+    let uref: URef = key.try_into().unwrap_or_revert();
+    storage::read(uref).unwrap_or_revert()
+}
+
+/// Creates a dictionary item key for a dictionary keyed by account or contract, by base64 encoding
+/// the serialized [`Key`] since stringified Keys are too long to be used as dictionary keys.
+#[inline]
+pub(crate) fn make_dictionary_item_key(key: Key) -> String {
+    let preimage = key.to_bytes().unwrap_or_revert();
+    // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
+    // hashing (which will effectively hash a hash) we'll use base64. Preimage is 33 bytes for
+    // both used Key variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
+    // characters.
+    // Even if the preimage increased in size we still have extra space but even in case of much
+    // larger preimage we can switch to base85 which has ratio of 4:5.
+    base64::encode(preimage)
+}
+
+/// Creates a dictionary item key for a dictionary keyed by an arbitrary string chosen by a caller,
+/// by hex encoding the hash of the string so that it fits the dictionary item key length limit.
+pub(crate) fn make_string_dictionary_item_key(id: &str) -> String {
+    hex::encode(runtime::blake2b(id.as_bytes()))
+}
+
 /// Returns address based on a [`CallStackElement`].
 ///
 /// For `Session` and `StoredSession` variants it will return account hash, and for `StoredContract`
@@ -86,6 +128,35 @@ pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
     storage::write(uref, value);
 }
 
+/// Converts a token amount into motes.
+pub(crate) fn u256_to_u512(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// Returns `true` if `signature`, a serialized [`Signature`], was made over `hash` with the secret
+/// key of `public_key`.
+pub(crate) fn verify_signature(hash: [u8; 32], public_key: &PublicKey, signature: &[u8]) -> bool {
+    bytesrepr::deserialize::<Signature>(signature.to_vec())
+        .map(|signature| crypto::verify(hash, &signature, public_key).is_ok())
+        .unwrap_or(false)
+}
+
+/// Calls `entry_point` of the CEP-18 token in the contract package `token`.
+pub(crate) fn call_token<T: CLTyped + FromBytes>(
+    token: Key,
+    entry_point: &str,
+    args: RuntimeArgs,
+) -> T {
+    let token = token
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .ok_or(ApiError::UnexpectedKeyVariant)
+        .unwrap_or_revert();
+    runtime::call_versioned_contract(token, None, entry_point, args)
+}
+
 pub fn get_named_arg_size(name: &str) -> Option<usize> {
     let mut arg_size: usize = 0;
     let ret = unsafe {
@@ -118,7 +189,13 @@ pub fn get_named_arg_with_user_errors<T: FromBytes>(
     missing: Cep18Error,
     invalid: Cep18Error,
 ) -> Result<T, Cep18Error> {
-    let arg_size = get_named_arg_size(name).ok_or(missing)?;
+    let arg_bytes = get_named_arg_bytes(name).ok_or(missing)?;
+    bytesrepr::deserialize(arg_bytes).map_err(|_| invalid)
+}
+
+/// Reads the serialized bytes of a named argument, returning `None` if it was not passed.
+pub(crate) fn get_named_arg_bytes(name: &str) -> Option<Vec<u8>> {
+    let arg_size = get_named_arg_size(name)?;
     let arg_bytes = if arg_size > 0 {
         let res = {
             let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
@@ -140,8 +217,7 @@ pub fn get_named_arg_with_user_errors<T: FromBytes>(
         // Avoids allocation with 0 bytes and a call to get_named_arg
         Vec::new()
     };
-
-    bytesrepr::deserialize(arg_bytes).map_err(|_| invalid)
+    Some(arg_bytes)
 }
 
 #[repr(u8)]
@@ -150,6 +226,8 @@ pub enum SecurityBadge {
     Admin = 0,
     Minter = 1,
     None = 2,
+    Rebaser = 3,
+    Bridge = 4,
 }
 
 impl CLTyped for SecurityBadge {
@@ -175,6 +253,8 @@ impl FromBytes for SecurityBadge {
                 0 => SecurityBadge::Admin,
                 1 => SecurityBadge::Minter,
                 2 => SecurityBadge::None,
+                3 => SecurityBadge::Rebaser,
+                4 => SecurityBadge::Bridge,
                 _ => return Err(bytesrepr::Error::LeftOverBytes),
             },
             &[],
@@ -196,6 +276,15 @@ pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
     }
 }
 
+/// Returns the security badge held by `address`, if any.
+pub(crate) fn read_sec_badge(address: Key) -> Option<SecurityBadge> {
+    dictionary_get::<SecurityBadge>(
+        get_uref(SECURITY_BADGES),
+        &make_dictionary_item_key(address),
+    )
+    .unwrap_or_revert()
+}
+
 pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
     let sec_uref = get_uref(SECURITY_BADGES);
     for (&user, &badge) in badge_map {
@@ -0,0 +1,73 @@
+//! Implementation of mint vouchers, which let a minter authorize mints off-chain by signing them,
+//! and anyone submit them on-chain.
+//!
+//! A voucher signs `blake2b(bytes)`, where `bytes` is the serialized
+//! `(package_hash, recipient, amount, nonce, expiry)` tuple, so that it cannot be replayed against
+//! another token. Each nonce can be redeemed once per signer.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{
+        runtime,
+        storage::{dictionary_get, dictionary_put},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, PublicKey, U256};
+
+use crate::{
+    constants::REDEEMED_VOUCHERS,
+    error::Cep18Error,
+    utils::{self, get_uref, SecurityBadge},
+};
+
+/// Returns the hash a voucher for `amount` tokens to `recipient` has to be signed over.
+fn voucher_hash(recipient: Key, amount: U256, nonce: u64, expiry: u64) -> [u8; 32] {
+    let bytes = (utils::get_self_key(), recipient, amount, nonce, expiry)
+        .to_bytes()
+        .unwrap_or_revert();
+    runtime::blake2b(bytes)
+}
+
+fn make_nonce_item_key(signer: Key, nonce: u64) -> String {
+    let mut preimage: Vec<u8> = signer.to_bytes().unwrap_or_revert();
+    preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+    hex::encode(runtime::blake2b(preimage))
+}
+
+/// Verifies a voucher signed by `signer_public_key` and marks its nonce as redeemed. Returns the
+/// account of the signer, which has to hold the `Minter` badge. The caller mints the tokens.
+pub(crate) fn redeem(
+    recipient: Key,
+    amount: U256,
+    nonce: u64,
+    expiry: u64,
+    signer_public_key: &PublicKey,
+    signature: &[u8],
+) -> Result<Key, Cep18Error> {
+    if u64::from(runtime::get_blocktime()) > expiry {
+        return Err(Cep18Error::VoucherExpired);
+    }
+    let signer = Key::Account(signer_public_key.to_account_hash());
+    if utils::read_sec_badge(signer) != Some(SecurityBadge::Minter) {
+        return Err(Cep18Error::InvalidVoucherSigner);
+    }
+    if !utils::verify_signature(
+        voucher_hash(recipient, amount, nonce, expiry),
+        signer_public_key,
+        signature,
+    ) {
+        return Err(Cep18Error::InvalidVoucherSignature);
+    }
+
+    let redeemed_uref = get_uref(REDEEMED_VOUCHERS);
+    let item_key = make_nonce_item_key(signer, nonce);
+    if dictionary_get::<bool>(redeemed_uref, &item_key)
+        .unwrap_or_revert()
+        .is_some()
+    {
+        return Err(Cep18Error::VoucherAlreadyRedeemed);
+    }
+    dictionary_put(redeemed_uref, &item_key, true);
+    Ok(signer)
+}
@@ -0,0 +1,152 @@
+//! Implementation of the wrapper modes, in which tokens are minted against assets deposited into
+//! the contract and burned when those assets are withdrawn.
+//!
+//! In the `Vault` mode the tokens are shares of the underlying CEP-18 tokens held under the
+//! contract's own package key, whose balance can grow, e.g. with yield, independently of the share
+//! supply. Conversions between assets and shares follow ERC-4626, with one virtual share and one
+//! virtual asset so that the first depositor cannot inflate the share price at the expense of later
+//! ones.
+//!
+//! In the `Cep18` mode the tokens wrap another CEP-18 token 1:1, e.g. to give a legacy token events
+//! and the other modalities of this contract. After every mint and unwrap the contract checks that
+//! the wrapped supply is still fully backed by the underlying tokens it holds, which rejects
+//! underlying tokens that deliver less than the transferred amount, e.g. because of transfer fees.
+//! Installation rejects an initial supply, rebasing and every other way of minting in the wrapper
+//! modes, so the check cannot be broken by tokens minted without a deposit.
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::system, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, Key, RuntimeArgs, URef, U256};
+
+use crate::{
+    constants::{
+        ADDRESS, AMOUNT, BALANCE_OF_ENTRY_POINT_NAME, OWNER, RECIPIENT, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME, UNDERLYING_TOKEN, WRAPPER_MODE, WRAPPER_PURSE,
+    },
+    error::Cep18Error,
+    modalities::WrapperMode,
+    rebasing::{self, Rounding},
+    utils::{self, get_uref, read_from, read_optional_from},
+};
+
+/// Returns the wrapper mode of this contract instance.
+pub(crate) fn read_wrapper_mode() -> WrapperMode {
+    WrapperMode::try_from(read_optional_from::<u8>(WRAPPER_MODE).unwrap_or_default())
+        .unwrap_or_revert()
+}
+
+/// Getter for the purse holding the motes backing wrapped CSPR.
+pub(crate) fn get_wrapper_purse() -> URef {
+    get_uref(WRAPPER_PURSE)
+}
+
+fn read_underlying_token() -> Key {
+    read_from(UNDERLYING_TOKEN)
+}
+
+/// Returns the number of underlying tokens held by the contract.
+pub(crate) fn total_assets() -> U256 {
+    utils::call_token(
+        read_underlying_token(),
+        BALANCE_OF_ENTRY_POINT_NAME,
+        runtime_args! {
+            ADDRESS => utils::get_self_key(),
+        },
+    )
+}
+
+/// Checks that the wrapped supply does not exceed the motes or underlying tokens held by the
+/// contract. Vault shares are not backed 1:1, so there is nothing to check in the `Vault` mode.
+pub(crate) fn check_backing() -> Result<(), Cep18Error> {
+    let total_supply = rebasing::read_total_supply_amount();
+    let backed = match read_wrapper_mode() {
+        WrapperMode::Cspr => {
+            utils::u256_to_u512(total_supply)
+                <= system::get_purse_balance(get_wrapper_purse()).unwrap_or_revert()
+        }
+        WrapperMode::Cep18 => total_supply <= total_assets(),
+        WrapperMode::Vault | WrapperMode::Disabled => true,
+    };
+    if !backed {
+        return Err(Cep18Error::WrapperInvariantViolated);
+    }
+    Ok(())
+}
+
+/// Computes `value * numerator / denominator` with the given rounding.
+fn mul_div(
+    value: U256,
+    numerator: U256,
+    denominator: U256,
+    rounding: Rounding,
+) -> Result<U256, Cep18Error> {
+    let (quotient, remainder) = value
+        .checked_mul(numerator)
+        .ok_or(Cep18Error::Overflow)?
+        .div_mod(denominator);
+    match rounding {
+        Rounding::Up if !remainder.is_zero() => Ok(quotient + 1),
+        _ => Ok(quotient),
+    }
+}
+
+/// Converts a number of underlying tokens into vault shares, given the underlying tokens held by
+/// the contract.
+fn convert_to_shares_with(
+    assets: U256,
+    total_assets: U256,
+    rounding: Rounding,
+) -> Result<U256, Cep18Error> {
+    let total_supply = rebasing::read_total_supply_amount();
+    mul_div(assets, total_supply + 1, total_assets + 1, rounding)
+}
+
+/// Converts a number of underlying tokens into vault shares.
+pub(crate) fn convert_to_shares(assets: U256, rounding: Rounding) -> Result<U256, Cep18Error> {
+    convert_to_shares_with(assets, total_assets(), rounding)
+}
+
+/// Converts a number of vault shares into underlying tokens.
+pub(crate) fn convert_to_assets(shares: U256, rounding: Rounding) -> Result<U256, Cep18Error> {
+    let total_supply = rebasing::read_total_supply_amount();
+    mul_div(shares, total_assets() + 1, total_supply + 1, rounding)
+}
+
+/// Takes `assets` underlying tokens from `owner` with `transfer_from`, which requires this
+/// contract package to have been approved to spend them.
+pub(crate) fn pull_assets(owner: Key, assets: U256) {
+    utils::call_token::<()>(
+        read_underlying_token(),
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            OWNER => owner,
+            RECIPIENT => utils::get_self_key(),
+            AMOUNT => assets,
+        },
+    )
+}
+
+/// Takes `assets` underlying tokens from `owner` into the vault. Returns the number of underlying
+/// tokens the vault actually received, which is less than `assets` for underlying tokens with e.g.
+/// transfer fees, and the vault shares they are worth, rounded down.
+pub(crate) fn pull_vault_assets(owner: Key, assets: U256) -> Result<(U256, U256), Cep18Error> {
+    let total_assets_before = total_assets();
+    pull_assets(owner, assets);
+    let received = total_assets()
+        .checked_sub(total_assets_before)
+        .ok_or(Cep18Error::WrapperInvariantViolated)?;
+    let shares = convert_to_shares_with(received, total_assets_before, Rounding::Down)?;
+    Ok((received, shares))
+}
+
+/// Sends `assets` underlying tokens to `recipient`.
+pub(crate) fn push_assets(recipient: Key, assets: U256) {
+    utils::call_token::<()>(
+        read_underlying_token(),
+        TRANSFER_ENTRY_POINT_NAME,
+        runtime_args! {
+            RECIPIENT => recipient,
+            AMOUNT => assets,
+        },
+    )
+}
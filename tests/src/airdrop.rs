@@ -0,0 +1,187 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ACCOUNT, ARG_AMOUNT, ARG_INDEX, ARG_PROOF, ARG_ROOT,
+        ARG_TOTAL, ENABLE_MINT_BURN, ENABLE_REBASING, ERROR_AIRDROP_ALREADY_CLAIMED,
+        ERROR_AIRDROP_WITH_REBASING, ERROR_INVALID_MERKLE_PROOF, ERROR_MINT_WINDOW_LIMIT_EXCEEDED,
+        METHOD_CLAIM, METHOD_SET_MERKLE_ROOT, MINT_WINDOW_LIMIT, MINT_WINDOW_SECONDS,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_total_supply, setup,
+        setup_with_modalities, TestContext,
+    },
+    merkle::MerkleTree,
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+fn airdrop_leaves() -> Vec<(Key, U256)> {
+    vec![
+        (Key::Account(*ACCOUNT_1_ADDR), U256::from(100)),
+        (Key::Account(*ACCOUNT_2_ADDR), U256::from(200)),
+        (Key::Account(*DEFAULT_ACCOUNT_ADDR), U256::from(300)),
+    ]
+}
+
+fn airdrop_total() -> U256 {
+    airdrop_leaves().iter().map(|(_, amount)| *amount).sum()
+}
+
+fn set_merkle_root(builder: &mut InMemoryWasmTestBuilder, cep18_token: ContractHash) -> MerkleTree {
+    let tree = MerkleTree::new(&airdrop_leaves());
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MERKLE_ROOT,
+        runtime_args! {
+            ARG_ROOT => tree.root(),
+            ARG_TOTAL => airdrop_total(),
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+    tree
+}
+
+fn make_claim_request(
+    cep18_token: ContractHash,
+    tree: &MerkleTree,
+    index: u64,
+    amount: U256,
+) -> ExecuteRequest {
+    let (account, _) = airdrop_leaves()[index as usize];
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CLAIM,
+        runtime_args! {
+            ARG_INDEX => index,
+            ARG_ACCOUNT => account,
+            ARG_AMOUNT => amount,
+            ARG_PROOF => tree.proof(index),
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_claim_airdrop_from_escrow() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let tree = set_merkle_root(&mut builder, cep18_token);
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY) - airdrop_total()
+    );
+
+    for (index, (account, amount)) in airdrop_leaves().into_iter().enumerate() {
+        builder
+            .exec(make_claim_request(cep18_token, &tree, index as u64, amount))
+            .expect_success()
+            .commit();
+        if index < 2 {
+            assert_eq!(
+                cep18_check_balance_of(&mut builder, &cep18_token, account),
+                amount
+            );
+        }
+    }
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &cep18_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(TOKEN_TOTAL_SUPPLY) - U256::from(300)
+    );
+
+    builder
+        .exec(make_claim_request(cep18_token, &tree, 0, U256::from(100)))
+        .commit();
+    assert_user_error(&builder, ERROR_AIRDROP_ALREADY_CLAIMED);
+}
+
+#[test]
+fn should_mint_claims_in_mint_burn_mode() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+    });
+    let tree = set_merkle_root(&mut builder, cep18_token);
+
+    builder
+        .exec(make_claim_request(cep18_token, &tree, 1, U256::from(200)))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(200)
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY + 200)
+    );
+}
+
+#[test]
+fn should_count_minted_claims_towards_mint_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        MINT_WINDOW_SECONDS => 3_600u64,
+        MINT_WINDOW_LIMIT => U256::from(250),
+    });
+    let tree = set_merkle_root(&mut builder, cep18_token);
+
+    builder
+        .exec(make_claim_request(cep18_token, &tree, 1, U256::from(200)))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_claim_request(cep18_token, &tree, 0, U256::from(100)))
+        .commit();
+    assert_user_error(&builder, ERROR_MINT_WINDOW_LIMIT_EXCEEDED);
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_claim_with_invalid_proof() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let tree = set_merkle_root(&mut builder, cep18_token);
+
+    builder
+        .exec(make_claim_request(cep18_token, &tree, 1, U256::from(2_000)))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_MERKLE_PROOF);
+}
+
+#[test]
+fn should_not_escrow_airdrop_with_rebasing() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_REBASING => 1u8,
+    });
+
+    let tree = MerkleTree::new(&airdrop_leaves());
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_MERKLE_ROOT,
+        runtime_args! {
+            ARG_ROOT => tree.root(),
+            ARG_TOTAL => airdrop_total(),
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+    assert_user_error(&builder, ERROR_AIRDROP_WITH_REBASING);
+}
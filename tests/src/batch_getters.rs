@@ -0,0 +1,83 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWANCE_AMOUNT_1, ARG_ADDRESSES, ERROR_BATCH_TOO_LARGE,
+        MAX_BATCH_LENGTH, METHOD_BALANCES_OF, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_allowances_of, cep18_check_balances_of,
+        make_cep18_approve_request, make_cep18_transfer_request, setup, TestContext,
+    },
+};
+
+#[test]
+fn should_return_balances_and_allowances_in_batch() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_token,
+        account_1,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    let approve_request = make_cep18_approve_request(
+        owner,
+        &cep18_token,
+        account_2,
+        U256::from(ALLOWANCE_AMOUNT_1),
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balances_of(
+            &mut builder,
+            &cep18_token,
+            vec![owner, account_1, account_2]
+        ),
+        vec![
+            U256::from(TOKEN_TOTAL_SUPPLY) - TRANSFER_AMOUNT_1,
+            U256::from(TRANSFER_AMOUNT_1),
+            U256::zero(),
+        ]
+    );
+    assert_eq!(
+        cep18_check_allowances_of(
+            &mut builder,
+            &cep18_token,
+            vec![(owner, account_2), (owner, account_1)]
+        ),
+        vec![U256::from(ALLOWANCE_AMOUNT_1), U256::zero()]
+    );
+    assert_eq!(
+        cep18_check_balances_of(&mut builder, &cep18_token, Vec::new()),
+        Vec::<U256>::new()
+    );
+}
+
+#[test]
+fn should_not_return_more_balances_than_allowed() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let addresses = vec![Key::Account(*ACCOUNT_1_ADDR); MAX_BATCH_LENGTH];
+    assert_eq!(
+        cep18_check_balances_of(&mut builder, &cep18_token, addresses.clone()).len(),
+        MAX_BATCH_LENGTH
+    );
+
+    let mut addresses = addresses;
+    addresses.push(Key::Account(*ACCOUNT_2_ADDR));
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BALANCES_OF,
+        runtime_args! { ARG_ADDRESSES => addresses },
+    )
+    .build();
+    builder.exec(request).commit();
+    assert_user_error(&builder, ERROR_BATCH_TOO_LARGE);
+}
@@ -0,0 +1,136 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DESTINATION_ADDRESS, ARG_DESTINATION_CHAIN,
+        ARG_EXTERNAL_REF, ARG_RECIPIENT, BRIDGE_LIST, CHANGE_SECURITY, ENABLE_MINT_BURN,
+        ERROR_EXTERNAL_REF_ALREADY_PROCESSED, ERROR_INSUFFICIENT_RIGHTS, METHOD_BRIDGE_BURN,
+        METHOD_BRIDGE_MINT, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_total_supply, setup_with_modalities,
+        TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const EXTERNAL_REF: &str = "ethereum:0x5c1f3fd6a7b40d0e0b4b0f2c5d5f6e7a8b9ce2a9:17";
+
+fn make_bridge_mint_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    recipient: Key,
+    amount: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        cep18_token,
+        METHOD_BRIDGE_MINT,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+            ARG_EXTERNAL_REF => EXTERNAL_REF,
+        },
+    )
+    .build()
+}
+
+fn grant_bridge_badge(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    bridge: Key,
+) {
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            BRIDGE_LIST => vec![bridge],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_bridge_mint_each_external_ref_once() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+    });
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    grant_bridge_badge(&mut builder, cep18_token, Key::Account(*ACCOUNT_1_ADDR));
+
+    let bridge_mint_request =
+        make_bridge_mint_request(*ACCOUNT_1_ADDR, cep18_token, recipient, U256::from(500));
+    builder.exec(bridge_mint_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(500)
+    );
+
+    let retry_request =
+        make_bridge_mint_request(*ACCOUNT_1_ADDR, cep18_token, recipient, U256::from(500));
+    builder.exec(retry_request).commit();
+
+    assert_user_error(&builder, ERROR_EXTERNAL_REF_ALREADY_PROCESSED);
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) + 500
+    );
+}
+
+#[test]
+fn should_not_bridge_mint_without_bridge_badge() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+    });
+
+    // Admins cannot bridge mint either.
+    let bridge_mint_request = make_bridge_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(500),
+    );
+    builder.exec(bridge_mint_request).commit();
+
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+}
+
+#[test]
+fn should_bridge_burn_callers_tokens() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let bridge_burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BRIDGE_BURN,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(300),
+            ARG_DESTINATION_CHAIN => "ethereum",
+            ARG_DESTINATION_ADDRESS => "0x5c1f3fd6a7b40d0e0b4b0f2c5d5f6e7a8b9ce2a9",
+        },
+    )
+    .build();
+    builder.exec(bridge_burn_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - 300
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) - 300
+    );
+}
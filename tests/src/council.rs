@@ -0,0 +1,331 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_LIST, ARG_ACTION, ARG_AMOUNT, ARG_ARGS, ARG_DECIMALS,
+        ARG_NAME, ARG_OWNER, ARG_PROPOSAL_ID, ARG_RECIPIENT, ARG_ROOT, ARG_SYMBOL, ARG_TOTAL,
+        ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM, COUNCIL, COUNCIL_THRESHOLD, ENABLE_MINT_BURN,
+        ERROR_AIRDROP_ESCROW_FROM_SELF, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_ADMIN_LIST,
+        ERROR_INVALID_COUNCIL_THRESHOLD, ERROR_INVALID_PROPOSAL, ERROR_NOT_COUNCIL_MEMBER,
+        ERROR_PROPOSAL_ALREADY_EXECUTED, ERROR_PROPOSAL_THRESHOLD_NOT_MET, METHOD_APPROVE_PROPOSAL,
+        METHOD_CHANGE_COUNCIL, METHOD_CREATE_PROPOSAL, METHOD_EXECUTE_PROPOSAL, METHOD_MINT,
+        METHOD_SET_MERKLE_ROOT, METHOD_TRANSFER, TOKEN_DECIMALS, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, setup, setup_with_modalities, TestContext,
+    },
+};
+
+const MINT_AMOUNT: u64 = 100;
+
+fn call(
+    builder: &mut InMemoryWasmTestBuilder,
+    context: &TestContext,
+    sender: AccountHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        context.cep18_token,
+        entry_point,
+        args,
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn propose(
+    builder: &mut InMemoryWasmTestBuilder,
+    context: &TestContext,
+    action: &str,
+    args: RuntimeArgs,
+) {
+    let args = Bytes::from(args.to_bytes().expect("should serialize args"));
+    call(
+        builder,
+        context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_CREATE_PROPOSAL,
+        runtime_args! { ARG_ACTION => action.to_string(), ARG_ARGS => args },
+    );
+    builder.expect_success();
+}
+
+#[test]
+fn should_mint_once_proposal_meets_threshold() {
+    let (mut builder, context) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        COUNCIL => vec![
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(*ACCOUNT_1_ADDR),
+            Key::Account(*ACCOUNT_2_ADDR),
+        ],
+        COUNCIL_THRESHOLD => 2u32,
+    });
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    propose(
+        &mut builder,
+        &context,
+        METHOD_MINT,
+        runtime_args! { ARG_OWNER => recipient, ARG_AMOUNT => U256::from(MINT_AMOUNT) },
+    );
+    call(
+        &mut builder,
+        &context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    assert_user_error(&builder, ERROR_PROPOSAL_THRESHOLD_NOT_MET);
+
+    call(
+        &mut builder,
+        &context,
+        *ACCOUNT_1_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    builder.expect_success();
+    call(
+        &mut builder,
+        &context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    builder.expect_success();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &context.cep18_token, recipient),
+        U256::from(MINT_AMOUNT)
+    );
+
+    call(
+        &mut builder,
+        &context,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    assert_user_error(&builder, ERROR_PROPOSAL_ALREADY_EXECUTED);
+}
+
+#[test]
+fn should_not_call_admin_entry_points_directly() {
+    let (mut builder, context) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        COUNCIL => vec![
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(*ACCOUNT_1_ADDR),
+            Key::Account(*ACCOUNT_2_ADDR),
+        ],
+        COUNCIL_THRESHOLD => 2u32,
+    });
+
+    call(
+        &mut builder,
+        &context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_AMOUNT => U256::from(MINT_AMOUNT),
+        },
+    );
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    call(
+        &mut builder,
+        &context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_CHANGE_COUNCIL,
+        runtime_args! {
+            COUNCIL => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+            COUNCIL_THRESHOLD => 1u32,
+        },
+    );
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+}
+
+#[test]
+fn should_change_council_through_proposal() {
+    let (mut builder, context) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        COUNCIL => vec![
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(*ACCOUNT_1_ADDR),
+            Key::Account(*ACCOUNT_2_ADDR),
+        ],
+        COUNCIL_THRESHOLD => 2u32,
+    });
+
+    propose(
+        &mut builder,
+        &context,
+        METHOD_CHANGE_COUNCIL,
+        runtime_args! {
+            COUNCIL => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR), Key::Account(*ACCOUNT_1_ADDR)],
+            COUNCIL_THRESHOLD => 1u32,
+        },
+    );
+    call(
+        &mut builder,
+        &context,
+        *ACCOUNT_2_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    builder.expect_success();
+    call(
+        &mut builder,
+        &context,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    builder.expect_success();
+
+    // The removed member can no longer propose, and a single approval now suffices.
+    call(
+        &mut builder,
+        &context,
+        *ACCOUNT_2_ADDR,
+        METHOD_CREATE_PROPOSAL,
+        runtime_args! {
+            ARG_ACTION => METHOD_MINT.to_string(),
+            ARG_ARGS => Bytes::from(RuntimeArgs::new().to_bytes().unwrap()),
+        },
+    );
+    assert_user_error(&builder, ERROR_NOT_COUNCIL_MEMBER);
+
+    propose(
+        &mut builder,
+        &context,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(MINT_AMOUNT),
+        },
+    );
+    call(
+        &mut builder,
+        &context,
+        *ACCOUNT_1_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 1u64 },
+    );
+    builder.expect_success();
+    assert_eq!(
+        cep18_check_balance_of(
+            &mut builder,
+            &context.cep18_token,
+            Key::Account(*ACCOUNT_1_ADDR)
+        ),
+        U256::from(MINT_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_propose_non_admin_entry_points() {
+    let (mut builder, context) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        COUNCIL => vec![
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Key::Account(*ACCOUNT_1_ADDR),
+            Key::Account(*ACCOUNT_2_ADDR),
+        ],
+        COUNCIL_THRESHOLD => 2u32,
+    });
+    let args = Bytes::from(
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(MINT_AMOUNT),
+        }
+        .to_bytes()
+        .expect("should serialize args"),
+    );
+
+    call(
+        &mut builder,
+        &context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_CREATE_PROPOSAL,
+        runtime_args! { ARG_ACTION => METHOD_TRANSFER.to_string(), ARG_ARGS => args },
+    );
+    assert_user_error(&builder, ERROR_INVALID_PROPOSAL);
+}
+
+#[test]
+fn should_not_escrow_airdrop_from_contract_itself() {
+    let (mut builder, context) = setup_with_modalities(runtime_args! {
+        COUNCIL => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        COUNCIL_THRESHOLD => 1u32,
+    });
+
+    propose(
+        &mut builder,
+        &context,
+        METHOD_SET_MERKLE_ROOT,
+        runtime_args! { ARG_ROOT => [0u8; 32], ARG_TOTAL => U256::from(MINT_AMOUNT) },
+    );
+    call(
+        &mut builder,
+        &context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! { ARG_PROPOSAL_ID => 0u64 },
+    );
+    assert_user_error(&builder, ERROR_AIRDROP_ESCROW_FROM_SELF);
+}
+
+#[test]
+fn should_not_install_with_threshold_above_council_size() {
+    let (mut builder, _) = setup();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => "CouncilTest",
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            COUNCIL => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+            COUNCIL_THRESHOLD => 2u32,
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_COUNCIL_THRESHOLD);
+}
+
+#[test]
+fn should_not_install_council_with_admin_list() {
+    let (mut builder, _) = setup();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => "CouncilTest",
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ENABLE_MINT_BURN => 1u8,
+            ADMIN_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+            COUNCIL => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR), Key::Account(*ACCOUNT_1_ADDR)],
+            COUNCIL_THRESHOLD => 2u32,
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_ADMIN_LIST);
+}
@@ -0,0 +1,194 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, DIVIDENDS_MODE, DIVIDEND_TOKEN, ERROR_DIVIDENDS_DISABLED,
+        ERROR_NO_DIVIDEND_TO_CLAIM, METHOD_CLAIM_DIVIDEND, METHOD_DISTRIBUTE, TOKEN_DECIMALS,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_withdrawable_dividend_of,
+        get_cep18_package_key, install_cep18_token, make_cep18_approve_request,
+        make_cep18_transfer_request, setup, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const DIVIDEND_TOKEN_NAME: &str = "DividendTest";
+const DIVIDEND_TOKEN_SUPPLY: u64 = 1_000;
+const DISTRIBUTION: u64 = 10_000;
+
+struct DividendsContext {
+    /// Token paying dividends to its holders.
+    dividend_token: ContractHash,
+    /// Token in which the dividends are paid.
+    revenue_token: ContractHash,
+}
+
+/// Installs a token paying dividends in the token of the default test setup.
+fn setup_dividends() -> (InMemoryWasmTestBuilder, DividendsContext) {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let revenue_package = get_cep18_package_key(&builder, cep18_token);
+
+    let dividend_token = install_cep18_token(
+        &mut builder,
+        runtime_args! {
+            ARG_NAME => DIVIDEND_TOKEN_NAME,
+            ARG_SYMBOL => "DIV",
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(DIVIDEND_TOKEN_SUPPLY),
+            DIVIDENDS_MODE => 2u8,
+            DIVIDEND_TOKEN => revenue_package,
+        },
+    );
+
+    (
+        builder,
+        DividendsContext {
+            dividend_token,
+            revenue_token: cep18_token,
+        },
+    )
+}
+
+/// Approves the dividend token to take `amount` revenue tokens and distributes them.
+fn distribute(builder: &mut InMemoryWasmTestBuilder, context: &DividendsContext, amount: u64) {
+    let dividend_package = get_cep18_package_key(builder, context.dividend_token);
+    let approve_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &context.revenue_token,
+        dividend_package,
+        U256::from(amount),
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    let distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        context.dividend_token,
+        METHOD_DISTRIBUTE,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .build();
+    builder.exec(distribute_request).expect_success().commit();
+}
+
+fn make_claim_request(sender: AccountHash, dividend_token: ContractHash) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        dividend_token,
+        METHOD_CLAIM_DIVIDEND,
+        RuntimeArgs::new(),
+    )
+    .build()
+}
+
+#[test]
+fn should_distribute_and_claim_dividends_pro_rata() {
+    let (mut builder, context) = setup_dividends();
+    let holder = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &context.dividend_token,
+        holder,
+        U256::from(250),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    distribute(&mut builder, &context, DISTRIBUTION);
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(&mut builder, &context.dividend_token, holder),
+        U256::from(2_500)
+    );
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(
+            &mut builder,
+            &context.dividend_token,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(7_500)
+    );
+
+    builder
+        .exec(make_claim_request(*ACCOUNT_1_ADDR, context.dividend_token))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &context.revenue_token, holder),
+        U256::from(2_500)
+    );
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(&mut builder, &context.dividend_token, holder),
+        U256::zero()
+    );
+
+    builder
+        .exec(make_claim_request(*ACCOUNT_1_ADDR, context.dividend_token))
+        .commit();
+    assert_user_error(&builder, ERROR_NO_DIVIDEND_TO_CLAIM);
+}
+
+#[test]
+fn should_keep_dividends_earned_before_transfer() {
+    let (mut builder, context) = setup_dividends();
+    let first_holder = Key::Account(*ACCOUNT_1_ADDR);
+    let second_holder = Key::Account(*ACCOUNT_2_ADDR);
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &context.dividend_token,
+        first_holder,
+        U256::from(250),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    distribute(&mut builder, &context, DISTRIBUTION);
+
+    let transfer_request = make_cep18_transfer_request(
+        first_holder,
+        &context.dividend_token,
+        second_holder,
+        U256::from(250),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(&mut builder, &context.dividend_token, first_holder),
+        U256::from(2_500)
+    );
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(&mut builder, &context.dividend_token, second_holder),
+        U256::zero()
+    );
+
+    distribute(&mut builder, &context, DISTRIBUTION);
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(&mut builder, &context.dividend_token, first_holder),
+        U256::from(2_500)
+    );
+    assert_eq!(
+        cep18_check_withdrawable_dividend_of(&mut builder, &context.dividend_token, second_holder),
+        U256::from(2_500)
+    );
+}
+
+#[test]
+fn should_not_distribute_when_dividends_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_DISTRIBUTE,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(DISTRIBUTION),
+        },
+    )
+    .build();
+    builder.exec(distribute_request).commit();
+    assert_user_error(&builder, ERROR_DIVIDENDS_DISABLED);
+}
@@ -0,0 +1,95 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_LIMIT, ARG_START, ENABLE_HOLDER_REGISTRY,
+        ERROR_BATCH_TOO_LARGE, ERROR_HOLDER_REGISTRY_DISABLED, MAX_BATCH_LENGTH, METHOD_HOLDERS,
+        METHOD_HOLDER_COUNT, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_holder_count, cep18_check_holders,
+        make_cep18_transfer_request, setup, setup_with_modalities, TestContext,
+    },
+};
+
+#[test]
+fn should_track_holders_as_balances_change() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_HOLDER_REGISTRY => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    assert_eq!(cep18_check_holder_count(&mut builder, &cep18_token), 1);
+    assert_eq!(
+        cep18_check_holders(&mut builder, &cep18_token, 0, 10),
+        vec![owner]
+    );
+
+    for recipient in [account_1, account_2] {
+        let transfer_request = make_cep18_transfer_request(
+            owner,
+            &cep18_token,
+            recipient,
+            U256::from(TRANSFER_AMOUNT_1),
+        );
+        builder.exec(transfer_request).expect_success().commit();
+    }
+    assert_eq!(cep18_check_holder_count(&mut builder, &cep18_token), 3);
+    assert_eq!(
+        cep18_check_holders(&mut builder, &cep18_token, 0, 10),
+        vec![owner, account_1, account_2]
+    );
+
+    // Emptying a balance moves the last holder into the freed index.
+    let transfer_request = make_cep18_transfer_request(
+        account_1,
+        &cep18_token,
+        owner,
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    assert_eq!(cep18_check_holder_count(&mut builder, &cep18_token), 2);
+    assert_eq!(
+        cep18_check_holders(&mut builder, &cep18_token, 0, 10),
+        vec![owner, account_2]
+    );
+    assert_eq!(
+        cep18_check_holders(&mut builder, &cep18_token, 1, 10),
+        vec![account_2]
+    );
+    assert_eq!(
+        cep18_check_holders(&mut builder, &cep18_token, 2, 10),
+        Vec::<Key>::new()
+    );
+
+    let holders_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HOLDERS,
+        runtime_args! {
+            ARG_START => 0u64,
+            ARG_LIMIT => MAX_BATCH_LENGTH as u64 + 1,
+        },
+    )
+    .build();
+    builder.exec(holders_request).commit();
+    assert_user_error(&builder, ERROR_BATCH_TOO_LARGE);
+}
+
+#[test]
+fn should_not_enumerate_holders_when_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let holder_count_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HOLDER_COUNT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(holder_count_request).commit();
+    assert_user_error(&builder, ERROR_HOLDER_REGISTRY_DISABLED);
+}
@@ -0,0 +1,162 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_EXPIRATION, ARG_HOLD_ID, ARG_NOTARY,
+        ARG_RECIPIENT, ERROR_HOLD_ALREADY_EXISTS, ERROR_HOLD_NOT_ACTIVE, ERROR_HOLD_NOT_EXPIRED,
+        ERROR_INSUFFICIENT_BALANCE, ERROR_INSUFFICIENT_RIGHTS, METHOD_EXECUTE_HOLD, METHOD_HOLD,
+        METHOD_RELEASE_HOLD, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_balance_on_hold,
+        make_cep18_transfer_request, setup, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const HOLD_ID: &str = "invoice-1";
+const HOLD_AMOUNT: u64 = 1_000;
+const EXPIRATION: u64 = 1_000_000;
+
+fn make_hold_request(cep18_token: ContractHash, expiration: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_HOLD,
+        runtime_args! {
+            ARG_HOLD_ID => HOLD_ID,
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_NOTARY => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::from(HOLD_AMOUNT),
+            ARG_EXPIRATION => expiration,
+        },
+    )
+    .build()
+}
+
+fn make_hold_id_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    entry_point: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        cep18_token,
+        entry_point,
+        runtime_args! {
+            ARG_HOLD_ID => HOLD_ID,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_hold_and_execute_to_recipient() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let payer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    builder
+        .exec(make_hold_request(cep18_token, 0))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_on_hold(&mut builder, &cep18_token, payer),
+        U256::from(HOLD_AMOUNT)
+    );
+
+    // Held tokens are not spendable.
+    let transfer_request = make_cep18_transfer_request(
+        payer,
+        &cep18_token,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(TOKEN_TOTAL_SUPPLY - HOLD_AMOUNT + 1),
+    );
+    builder.exec(transfer_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_BALANCE);
+
+    builder.exec(make_hold_request(cep18_token, 0)).commit();
+    assert_user_error(&builder, ERROR_HOLD_ALREADY_EXISTS);
+
+    // Only the notary can execute the hold.
+    builder
+        .exec(make_hold_id_request(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_EXECUTE_HOLD,
+            0,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    builder
+        .exec(make_hold_id_request(
+            *ACCOUNT_2_ADDR,
+            cep18_token,
+            METHOD_EXECUTE_HOLD,
+            0,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(HOLD_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_on_hold(&mut builder, &cep18_token, payer),
+        U256::zero()
+    );
+
+    builder
+        .exec(make_hold_id_request(
+            *ACCOUNT_2_ADDR,
+            cep18_token,
+            METHOD_EXECUTE_HOLD,
+            0,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_HOLD_NOT_ACTIVE);
+}
+
+#[test]
+fn should_let_payer_release_hold_only_after_expiration() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let payer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    builder
+        .exec(make_hold_request(cep18_token, EXPIRATION))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_hold_id_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_RELEASE_HOLD,
+            EXPIRATION - 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_HOLD_NOT_EXPIRED);
+
+    builder
+        .exec(make_hold_id_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_RELEASE_HOLD,
+            EXPIRATION,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_on_hold(&mut builder, &cep18_token, payer),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, payer),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+}
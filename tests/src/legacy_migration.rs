@@ -0,0 +1,165 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM,
+        ERROR_INVALID_LEGACY_RATIO, ERROR_LEGACY_MIGRATION_CLOSED, ERROR_LEGACY_MIGRATION_DISABLED,
+        ERROR_MINT_WINDOW_LIMIT_EXCEEDED, LEGACY_RATIO_DENOMINATOR, LEGACY_RATIO_NUMERATOR,
+        LEGACY_TOKEN, METHOD_CLOSE_LEGACY_MIGRATION, METHOD_MIGRATE_FROM_LEGACY, MINT_WINDOW_LIMIT,
+        MINT_WINDOW_SECONDS, TOKEN_DECIMALS, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, get_cep18_package_key, install_cep18_token,
+        make_cep18_approve_request, setup, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const NEW_TOKEN_NAME: &str = "MigratedTest";
+const LEGACY_AMOUNT: u64 = 100;
+/// Each legacy token is worth ten new tokens.
+const RATIO: u64 = 10;
+
+fn new_token_args(legacy_package: Key, numerator: U256) -> RuntimeArgs {
+    runtime_args! {
+        ARG_NAME => NEW_TOKEN_NAME,
+        ARG_SYMBOL => "MGR",
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::zero(),
+        LEGACY_TOKEN => legacy_package,
+        LEGACY_RATIO_NUMERATOR => numerator,
+        LEGACY_RATIO_DENOMINATOR => U256::one(),
+    }
+}
+
+/// Installs a new token migrating from the token of the default test setup, and approves it to
+/// take the legacy tokens of the default account.
+fn setup_migration() -> (InMemoryWasmTestBuilder, ContractHash, ContractHash) {
+    setup_migration_with_args(RuntimeArgs::new())
+}
+
+/// Like [`setup_migration`], with `extra_args` added to the install arguments of the new token.
+fn setup_migration_with_args(
+    extra_args: RuntimeArgs,
+) -> (InMemoryWasmTestBuilder, ContractHash, ContractHash) {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let legacy_package = get_cep18_package_key(&builder, cep18_token);
+
+    let mut install_args = new_token_args(legacy_package, U256::from(RATIO));
+    for named_arg in extra_args.named_args() {
+        install_args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+    }
+    let new_token = install_cep18_token(&mut builder, install_args);
+    let approve_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        get_cep18_package_key(&builder, new_token),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    (builder, new_token, cep18_token)
+}
+
+fn make_migrate_request(new_token: ContractHash, amount: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        new_token,
+        METHOD_MIGRATE_FROM_LEGACY,
+        runtime_args! { ARG_AMOUNT => U256::from(amount) },
+    )
+    .build()
+}
+
+#[test]
+fn should_migrate_from_legacy_token() {
+    let (mut builder, new_token, legacy_token) = setup_migration();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    builder
+        .exec(make_migrate_request(new_token, LEGACY_AMOUNT))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &new_token, owner),
+        U256::from(LEGACY_AMOUNT * RATIO)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &legacy_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY - LEGACY_AMOUNT)
+    );
+    let new_token_package = get_cep18_package_key(&builder, new_token);
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &legacy_token, new_token_package),
+        U256::from(LEGACY_AMOUNT)
+    );
+}
+
+#[test]
+fn should_count_migrations_towards_mint_window() {
+    let (mut builder, new_token, legacy_token) = setup_migration_with_args(runtime_args! {
+        MINT_WINDOW_SECONDS => 3_600u64,
+        MINT_WINDOW_LIMIT => U256::from(LEGACY_AMOUNT * RATIO),
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    builder
+        .exec(make_migrate_request(new_token, LEGACY_AMOUNT))
+        .expect_success()
+        .commit();
+
+    builder.exec(make_migrate_request(new_token, 1)).commit();
+    assert_user_error(&builder, ERROR_MINT_WINDOW_LIMIT_EXCEEDED);
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &legacy_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY - LEGACY_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_migrate_after_close() {
+    let (mut builder, new_token, _) = setup_migration();
+
+    let close_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        new_token,
+        METHOD_CLOSE_LEGACY_MIGRATION,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(close_request).expect_success().commit();
+
+    builder
+        .exec(make_migrate_request(new_token, LEGACY_AMOUNT))
+        .commit();
+    assert_user_error(&builder, ERROR_LEGACY_MIGRATION_CLOSED);
+}
+
+#[test]
+fn should_not_migrate_without_legacy_token() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    builder
+        .exec(make_migrate_request(cep18_token, LEGACY_AMOUNT))
+        .commit();
+    assert_user_error(&builder, ERROR_LEGACY_MIGRATION_DISABLED);
+}
+
+#[test]
+fn should_not_install_with_zero_ratio() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let legacy_package = get_cep18_package_key(&builder, cep18_token);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        new_token_args(legacy_package, U256::zero()),
+    )
+    .build();
+    builder.exec(install_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_LEGACY_RATIO);
+}
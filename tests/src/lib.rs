@@ -1,12 +1,62 @@
 #[cfg(test)]
+mod airdrop;
+#[cfg(test)]
 mod allowance;
 #[cfg(test)]
+mod batch_getters;
+#[cfg(test)]
+mod bridge;
+#[cfg(test)]
+mod council;
+#[cfg(test)]
+mod dividends;
+#[cfg(test)]
+mod holders;
+#[cfg(test)]
+mod holds;
+#[cfg(test)]
 mod install;
 #[cfg(test)]
+mod legacy_migration;
+#[cfg(test)]
+mod max_balance;
+#[cfg(test)]
+mod memo;
+#[cfg(test)]
+mod metadata;
+#[cfg(test)]
 mod migration;
 #[cfg(test)]
 mod mint_and_burn;
 #[cfg(test)]
+mod mint_window;
+#[cfg(test)]
+mod minter_allowances;
+#[cfg(test)]
+mod rebasing;
+#[cfg(test)]
+mod streams;
+#[cfg(test)]
+mod supply;
+#[cfg(test)]
+mod timelock;
+#[cfg(test)]
+mod token_info;
+#[cfg(test)]
 mod transfer;
 #[cfg(test)]
+mod transfer_fee;
+#[cfg(test)]
+mod transfer_limits;
+#[cfg(test)]
+mod transfer_with_authorization;
+#[cfg(test)]
 mod utility;
+#[cfg(test)]
+mod vault;
+#[cfg(test)]
+mod vouchers;
+#[cfg(test)]
+mod wrapped_cep18;
+#[cfg(test)]
+mod wrapped_cspr;
@@ -0,0 +1,109 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_OWNER, ENABLE_MAX_BALANCE, ENABLE_MINT_BURN,
+        ERROR_INSUFFICIENT_RIGHTS, ERROR_MAX_BALANCE_DISABLED, ERROR_MAX_BALANCE_EXCEEDED,
+        EXEMPT_LIST, MAX_BALANCE, METHOD_CHANGE_MAX_BALANCE, METHOD_MINT,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, make_cep18_transfer_request, setup,
+        setup_with_modalities, TestContext,
+    },
+};
+
+const MAX_HOLDER_BALANCE: u64 = 1_000;
+
+#[test]
+fn should_cap_recipient_balance_unless_exempt() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_MAX_BALANCE => 1u8,
+        MAX_BALANCE => U256::from(MAX_HOLDER_BALANCE),
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let holder = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, holder, U256::from(MAX_HOLDER_BALANCE));
+    builder.exec(transfer_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(owner, &cep18_token, holder, U256::one());
+    builder.exec(transfer_request).commit();
+    assert_user_error(&builder, ERROR_MAX_BALANCE_EXCEEDED);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => holder,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+    assert_user_error(&builder, ERROR_MAX_BALANCE_EXCEEDED);
+
+    let change_max_balance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_MAX_BALANCE,
+        runtime_args! {
+            EXEMPT_LIST => vec![holder],
+        },
+    )
+    .build();
+    builder
+        .exec(change_max_balance_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = make_cep18_transfer_request(owner, &cep18_token, holder, U256::one());
+    builder.exec(transfer_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, holder),
+        U256::from(MAX_HOLDER_BALANCE + 1)
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_change_max_balance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_MAX_BALANCE => 1u8,
+        MAX_BALANCE => U256::from(MAX_HOLDER_BALANCE),
+    });
+
+    let change_max_balance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CHANGE_MAX_BALANCE,
+        runtime_args! {
+            MAX_BALANCE => U256::MAX,
+        },
+    )
+    .build();
+    builder.exec(change_max_balance_request).commit();
+
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+}
+
+#[test]
+fn should_not_change_max_balance_with_mode_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let change_max_balance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_MAX_BALANCE,
+        runtime_args! {
+            MAX_BALANCE => U256::MAX,
+        },
+    )
+    .build();
+    builder.exec(change_max_balance_request).commit();
+
+    assert_user_error(&builder, ERROR_MAX_BALANCE_DISABLED);
+}
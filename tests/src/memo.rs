@@ -0,0 +1,168 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{bytesrepr::FromBytes, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_MEMO, ARG_OWNER, ARG_RECIPIENT,
+        ERROR_INVALID_MEMO, EVENTS_MODE, METHOD_TRANSFER_FROM_WITH_MEMO, METHOD_TRANSFER_WITH_MEMO,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_allowance_of, cep18_check_balance_of, get_last_event,
+        make_cep18_approve_request, setup, setup_with_modalities, TestContext,
+    },
+};
+
+const TRANSFER_AMOUNT: u64 = 1_000;
+const MEMO: &str = "invoice #42";
+
+/// The fields of a `TransferWithMemo` event, as recorded by the token.
+#[derive(Debug, PartialEq, Eq)]
+struct TransferWithMemo {
+    sender: Key,
+    spender: Option<Key>,
+    recipient: Key,
+    amount: U256,
+    fee: U256,
+    memo: String,
+}
+
+fn read_transfer_with_memo_event(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+) -> TransferWithMemo {
+    let event = get_last_event(builder, cep18_token);
+    let (name, remainder) = String::from_bytes(&event).expect("should have event name");
+    assert_eq!(name, "event_TransferWithMemo");
+    let (sender, remainder) = Key::from_bytes(remainder).expect("should have sender");
+    let (spender, remainder) = Option::<Key>::from_bytes(remainder).expect("should have spender");
+    let (recipient, remainder) = Key::from_bytes(remainder).expect("should have recipient");
+    let (amount, remainder) = U256::from_bytes(remainder).expect("should have amount");
+    let (fee, remainder) = U256::from_bytes(remainder).expect("should have fee");
+    let (memo, remainder) = String::from_bytes(remainder).expect("should have memo");
+    assert!(remainder.is_empty());
+    TransferWithMemo {
+        sender,
+        spender,
+        recipient,
+        amount,
+        fee,
+        memo,
+    }
+}
+
+#[test]
+fn should_transfer_with_memo() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        EVENTS_MODE => 1u8,
+    });
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_WITH_MEMO,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT),
+            ARG_MEMO => MEMO,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        read_transfer_with_memo_event(&builder, cep18_token),
+        TransferWithMemo {
+            sender,
+            spender: None,
+            recipient,
+            amount: U256::from(TRANSFER_AMOUNT),
+            fee: U256::zero(),
+            memo: MEMO.to_string(),
+        }
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - TRANSFER_AMOUNT
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(TRANSFER_AMOUNT)
+    );
+}
+
+#[test]
+fn should_transfer_from_with_memo() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        EVENTS_MODE => 1u8,
+    });
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_token, spender, U256::from(TRANSFER_AMOUNT));
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM_WITH_MEMO,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT),
+            ARG_MEMO => MEMO,
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        read_transfer_with_memo_event(&builder, cep18_token),
+        TransferWithMemo {
+            sender: owner,
+            spender: Some(spender),
+            recipient,
+            amount: U256::from(TRANSFER_AMOUNT),
+            fee: U256::zero(),
+            memo: MEMO.to_string(),
+        }
+    );
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(TRANSFER_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_transfer_with_too_long_memo() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_WITH_MEMO,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT),
+            ARG_MEMO => "x".repeat(257),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_failure().commit();
+
+    assert_user_error(&builder, ERROR_INVALID_MEMO);
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, RuntimeArgs};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_DESCRIPTION, ARG_ICON_URL, ARG_NAME, ARG_SYMBOL, ARG_WEBSITE,
+        ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_METADATA, METHOD_SET_METADATA, TOKEN_NAME,
+        TOKEN_SYMBOL,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_token_metadata, setup, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const ICON_URL: &str = "https://example.com/icon.svg";
+const WEBSITE: &str = "https://example.com";
+
+fn make_set_metadata_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    args: RuntimeArgs,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(sender, cep18_token, METHOD_SET_METADATA, args)
+        .build()
+}
+
+fn metadata(fields: &[(&str, &str)]) -> BTreeMap<String, String> {
+    fields
+        .iter()
+        .map(|(field, value)| (field.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn should_update_token_metadata() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    assert_eq!(
+        cep18_check_token_metadata(&mut builder, &cep18_token),
+        metadata(&[(ARG_NAME, TOKEN_NAME), (ARG_SYMBOL, TOKEN_SYMBOL)])
+    );
+
+    let request = make_set_metadata_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        runtime_args! {
+            ARG_NAME => "Casper Test Token",
+            ARG_ICON_URL => ICON_URL,
+            ARG_WEBSITE => WEBSITE,
+        },
+    );
+    builder.exec(request).expect_success().commit();
+    assert_eq!(
+        cep18_check_token_metadata(&mut builder, &cep18_token),
+        metadata(&[
+            (ARG_NAME, "Casper Test Token"),
+            (ARG_SYMBOL, TOKEN_SYMBOL),
+            (ARG_ICON_URL, ICON_URL),
+            (ARG_WEBSITE, WEBSITE),
+        ])
+    );
+
+    let request = make_set_metadata_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        runtime_args! {
+            ARG_SYMBOL => "CST",
+            ARG_WEBSITE => "",
+            ARG_DESCRIPTION => "A token for tests.",
+        },
+    );
+    builder.exec(request).expect_success().commit();
+    assert_eq!(
+        cep18_check_token_metadata(&mut builder, &cep18_token),
+        metadata(&[
+            (ARG_NAME, "Casper Test Token"),
+            (ARG_SYMBOL, "CST"),
+            (ARG_ICON_URL, ICON_URL),
+            (ARG_DESCRIPTION, "A token for tests."),
+        ])
+    );
+}
+
+#[test]
+fn should_not_set_invalid_metadata() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let request = make_set_metadata_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        runtime_args! { ARG_ICON_URL => ICON_URL },
+    );
+    builder.exec(request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    let request = make_set_metadata_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        runtime_args! { ARG_NAME => "" },
+    );
+    builder.exec(request).commit();
+    assert_user_error(&builder, ERROR_INVALID_METADATA);
+
+    let request = make_set_metadata_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        runtime_args! { ARG_DESCRIPTION => "x".repeat(1_025) },
+    );
+    builder.exec(request).commit();
+    assert_user_error(&builder, ERROR_INVALID_METADATA);
+}
@@ -1,12 +1,12 @@
 use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM, TOKEN_DECIMALS,
-        TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+        ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM,
+        CEP18_TOKEN_CONTRACT_KEY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
     },
-    installer_request_builders::{setup, TestContext},
+    installer_request_builders::{cep18_check_token_info, setup, TestContext},
 };
 
 #[test]
@@ -54,4 +54,17 @@ fn should_upgrade_contract_version() {
         .unwrap();
 
     assert!(version_0 < version_1);
+
+    let upgraded_token = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CEP18_TOKEN_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    let token_info = cep18_check_token_info(&mut builder, &upgraded_token);
+    assert_eq!(token_info.name, TOKEN_NAME);
+    assert_eq!(token_info.total_supply, U256::from(TOKEN_TOTAL_SUPPLY));
+    assert_eq!(token_info.icon_url, None);
 }
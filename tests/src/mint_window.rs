@@ -0,0 +1,157 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_OWNER, ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS,
+        ERROR_MINT_WINDOW_LIMIT_EXCEEDED, METHOD_CHANGE_MINT_WINDOW, METHOD_MINT,
+        MINT_WINDOW_LIMIT, MINT_WINDOW_SECONDS, TOKEN_OWNER_ADDRESS_1,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const WINDOW_SECONDS: u64 = 3_600;
+const WINDOW_START: u64 = 1_000_000;
+
+fn make_mint_request(cep18_token: ContractHash, amount: u64, block_time: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_limit_mints_per_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINT_WINDOW_SECONDS => WINDOW_SECONDS,
+        MINT_WINDOW_LIMIT => U256::from(1_000),
+    });
+
+    builder
+        .exec(make_mint_request(cep18_token, 600, WINDOW_START))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_mint_request(cep18_token, 500, WINDOW_START + 1_000))
+        .commit();
+    assert_user_error(&builder, ERROR_MINT_WINDOW_LIMIT_EXCEEDED);
+
+    // Mints no longer count once the window has elapsed since they were made.
+    builder
+        .exec(make_mint_request(
+            cep18_token,
+            1_000,
+            WINDOW_START + WINDOW_SECONDS * 1_000,
+        ))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_600)
+    );
+}
+
+#[test]
+fn should_limit_mints_in_rolling_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINT_WINDOW_SECONDS => WINDOW_SECONDS,
+        MINT_WINDOW_LIMIT => U256::from(1_000),
+    });
+    let window_millis = WINDOW_SECONDS * 1_000;
+
+    builder
+        .exec(make_mint_request(cep18_token, 400, WINDOW_START))
+        .expect_success()
+        .commit();
+    builder
+        .exec(make_mint_request(
+            cep18_token,
+            600,
+            WINDOW_START + window_millis - 1_000,
+        ))
+        .expect_success()
+        .commit();
+
+    // Only the first mint has left the window, so minting the full limit again is rejected.
+    builder
+        .exec(make_mint_request(
+            cep18_token,
+            1_000,
+            WINDOW_START + window_millis,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_MINT_WINDOW_LIMIT_EXCEEDED);
+
+    builder
+        .exec(make_mint_request(
+            cep18_token,
+            400,
+            WINDOW_START + window_millis,
+        ))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_400)
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_change_mint_window() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINT_WINDOW_SECONDS => WINDOW_SECONDS,
+        MINT_WINDOW_LIMIT => U256::from(1_000),
+    });
+
+    let non_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_CHANGE_MINT_WINDOW,
+        runtime_args! {
+            MINT_WINDOW_SECONDS => 0u64,
+        },
+    )
+    .build();
+    builder.exec(non_admin_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    let change_mint_window_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CHANGE_MINT_WINDOW,
+        runtime_args! {
+            MINT_WINDOW_LIMIT => U256::from(5_000),
+        },
+    )
+    .build();
+    builder
+        .exec(change_mint_window_request)
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_mint_request(cep18_token, 5_000, WINDOW_START))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(5_000)
+    );
+}
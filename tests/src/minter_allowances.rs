@@ -0,0 +1,146 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ALLOWANCE, ARG_AMOUNT, ARG_MINTER, ARG_OWNER, ENABLE_MINTER_ALLOWANCES,
+        ENABLE_MINT_BURN, ERROR_INSUFFICIENT_RIGHTS, ERROR_MINTER_ALLOWANCES_DISABLED,
+        ERROR_MINTER_ALLOWANCE_EXCEEDED, METHOD_CONFIGURE_MINTER, METHOD_MINT, MINTER_LIST,
+        TOKEN_OWNER_ADDRESS_1,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_minter_allowance,
+        setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+fn make_mint_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    amount: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build()
+}
+
+fn make_configure_minter_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    minter: Key,
+    allowance: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        cep18_token,
+        METHOD_CONFIGURE_MINTER,
+        runtime_args! {
+            ARG_MINTER => minter,
+            ARG_ALLOWANCE => allowance,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_limit_minters_to_their_allowance() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ENABLE_MINTER_ALLOWANCES => 1u8,
+    });
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+
+    // Minters start without any allowance.
+    builder
+        .exec(make_mint_request(*ACCOUNT_1_ADDR, cep18_token, U256::one()))
+        .commit();
+    assert_user_error(&builder, ERROR_MINTER_ALLOWANCE_EXCEEDED);
+
+    let configure_minter_request =
+        make_configure_minter_request(*DEFAULT_ACCOUNT_ADDR, cep18_token, minter, U256::from(100));
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_mint_request(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            U256::from(60),
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_minter_allowance(&mut builder, &cep18_token, minter),
+        U256::from(40)
+    );
+
+    builder
+        .exec(make_mint_request(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            U256::from(50),
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_MINTER_ALLOWANCE_EXCEEDED);
+
+    // Admins are not limited by mint allowances.
+    builder
+        .exec(make_mint_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            U256::from(1_000),
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(1_060)
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_configure_minters() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ENABLE_MINTER_ALLOWANCES => 1u8,
+    });
+    let minter = Key::Account(*ACCOUNT_1_ADDR);
+
+    let configure_minter_request =
+        make_configure_minter_request(*ACCOUNT_1_ADDR, cep18_token, minter, U256::from(100));
+    builder.exec(configure_minter_request).commit();
+
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+}
+
+#[test]
+fn should_not_configure_minter_with_allowances_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        ENABLE_MINTER_ALLOWANCES => 0u8,
+    });
+
+    let configure_minter_request = make_configure_minter_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(100),
+    );
+    builder.exec(configure_minter_request).commit();
+
+    assert_user_error(&builder, ERROR_MINTER_ALLOWANCES_DISABLED);
+}
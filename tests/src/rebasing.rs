@@ -0,0 +1,154 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_NEW_TOTAL_SUPPLY, CHANGE_SECURITY, ENABLE_MINT_BURN,
+        ENABLE_REBASING, ERROR_INSUFFICIENT_RIGHTS, METHOD_REBASE, REBASER_LIST,
+        TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_total_supply,
+        make_cep18_transfer_request, setup_with_modalities, TestContext,
+    },
+};
+
+#[test]
+fn should_scale_balances_and_total_supply_on_rebase() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_REBASING => 1u8,
+    });
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let transfer_request =
+        make_cep18_transfer_request(owner, &cep18_token, account_1, U256::from(1_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    let rebase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REBASE,
+        runtime_args! {
+            ARG_NEW_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY) * 2,
+        },
+    )
+    .build();
+    builder.exec(rebase_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) * 2
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        (U256::from(TOKEN_TOTAL_SUPPLY) - 1_000) * 2
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_1),
+        U256::from(2_000)
+    );
+
+    // The whole rebased balance can be transferred.
+    let transfer_request =
+        make_cep18_transfer_request(account_1, &cep18_token, account_2, U256::from(2_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_1),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_2),
+        U256::from(2_000)
+    );
+}
+
+#[test]
+fn should_round_transferred_shares_up() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_REBASING => 1u8,
+    });
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+
+    let rebase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_REBASE,
+        runtime_args! {
+            ARG_NEW_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY) * 3,
+        },
+    )
+    .build();
+    builder.exec(rebase_request).expect_success().commit();
+
+    // One token is less than a single share, so a whole share worth three tokens is moved.
+    let transfer_request = make_cep18_transfer_request(owner, &cep18_token, account_1, U256::one());
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, account_1),
+        U256::from(3)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) * 3 - 3
+    );
+}
+
+#[test]
+fn should_only_allow_rebaser_to_rebase() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_REBASING => 1u8,
+    });
+
+    let rebase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_REBASE,
+        runtime_args! {
+            ARG_NEW_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY) * 2,
+        },
+    )
+    .build();
+    builder.exec(rebase_request).commit();
+
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! {
+            REBASER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    let rebase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_REBASE,
+        runtime_args! {
+            ARG_NEW_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY) * 2,
+        },
+    )
+    .build();
+    builder.exec(rebase_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) * 2
+    );
+}
@@ -0,0 +1,204 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_END_TIME, ARG_RECIPIENT, ARG_START_TIME, ARG_STREAM_ID,
+        ENABLE_REBASING, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_STREAM, ERROR_STREAM_NOT_ACTIVE,
+        ERROR_STREAM_WITH_REBASING, METHOD_CANCEL_STREAM, METHOD_CREATE_STREAM,
+        METHOD_WITHDRAW_FROM_STREAM, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_stream_balance, setup,
+        setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const STREAM_ID: u64 = 0;
+const STREAM_AMOUNT: u64 = 1_000;
+const START_TIME: u64 = 10_000;
+const END_TIME: u64 = 20_000;
+
+fn make_create_stream_request(
+    cep18_token: ContractHash,
+    start_time: u64,
+    end_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(STREAM_AMOUNT),
+            ARG_START_TIME => start_time,
+            ARG_END_TIME => end_time,
+        },
+    )
+    .build()
+}
+
+fn make_stream_id_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    entry_point: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        cep18_token,
+        entry_point,
+        runtime_args! {
+            ARG_STREAM_ID => STREAM_ID,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_stream_linearly_to_recipient() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    builder
+        .exec(make_create_stream_request(
+            cep18_token,
+            START_TIME,
+            END_TIME,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - STREAM_AMOUNT
+    );
+    assert_eq!(
+        cep18_check_stream_balance(&mut builder, &cep18_token, STREAM_ID, START_TIME),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_stream_balance(&mut builder, &cep18_token, STREAM_ID, 12_500),
+        U256::from(250)
+    );
+
+    // Only the recipient can withdraw.
+    builder
+        .exec(make_stream_id_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_WITHDRAW_FROM_STREAM,
+            15_000,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    builder
+        .exec(make_stream_id_request(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_WITHDRAW_FROM_STREAM,
+            15_000,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(500)
+    );
+    assert_eq!(
+        cep18_check_stream_balance(&mut builder, &cep18_token, STREAM_ID, 15_000),
+        U256::zero()
+    );
+
+    builder
+        .exec(make_stream_id_request(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_WITHDRAW_FROM_STREAM,
+            END_TIME + 1,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(STREAM_AMOUNT)
+    );
+}
+
+#[test]
+fn should_split_cancelled_stream() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    builder
+        .exec(make_create_stream_request(
+            cep18_token,
+            START_TIME,
+            END_TIME,
+        ))
+        .expect_success()
+        .commit();
+    builder
+        .exec(make_stream_id_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            METHOD_CANCEL_STREAM,
+            14_000,
+        ))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(400)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - 400
+    );
+
+    builder
+        .exec(make_stream_id_request(
+            *ACCOUNT_1_ADDR,
+            cep18_token,
+            METHOD_WITHDRAW_FROM_STREAM,
+            END_TIME,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_STREAM_NOT_ACTIVE);
+}
+
+#[test]
+fn should_not_create_stream_ending_before_start() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    builder
+        .exec(make_create_stream_request(
+            cep18_token,
+            END_TIME,
+            START_TIME,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_STREAM);
+}
+
+#[test]
+fn should_not_create_stream_with_rebasing() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_REBASING => 1u8,
+    });
+
+    builder
+        .exec(make_create_stream_request(
+            cep18_token,
+            START_TIME,
+            END_TIME,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_STREAM_WITH_REBASING);
+}
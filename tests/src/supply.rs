@@ -0,0 +1,125 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_OWNER, ENABLE_HOLDER_REGISTRY, ENABLE_MINT_BURN,
+        ERROR_HOLDER_REGISTRY_DISABLED, ERROR_INSUFFICIENT_RIGHTS, METHOD_AUDIT_SUPPLY,
+        METHOD_BURN, METHOD_MINT, TOKEN_TOTAL_SUPPLY, TRANSFER_AMOUNT_1,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_total_burned, cep18_check_total_minted,
+        cep18_check_total_supply, make_cep18_transfer_request, setup, setup_with_modalities,
+        TestContext,
+    },
+};
+
+const MINT_AMOUNT: u64 = 100;
+const BURN_AMOUNT: u64 = 40;
+
+fn mint_and_burn(builder: &mut InMemoryWasmTestBuilder, cep18_token: ContractHash) {
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(MINT_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_AMOUNT => U256::from(BURN_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+}
+
+#[test]
+fn should_count_minted_and_burned_tokens() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_HOLDER_REGISTRY => 1u8,
+    });
+
+    assert_eq!(
+        cep18_check_total_minted(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        cep18_check_total_burned(&mut builder, &cep18_token),
+        U256::zero()
+    );
+
+    mint_and_burn(&mut builder, cep18_token);
+
+    let total_minted = cep18_check_total_minted(&mut builder, &cep18_token);
+    let total_burned = cep18_check_total_burned(&mut builder, &cep18_token);
+    assert_eq!(total_minted, U256::from(TOKEN_TOTAL_SUPPLY) + MINT_AMOUNT);
+    assert_eq!(total_burned, U256::from(BURN_AMOUNT));
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        total_minted - total_burned
+    );
+}
+
+#[test]
+fn should_audit_supply_against_holder_balances() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        ENABLE_HOLDER_REGISTRY => 1u8,
+    });
+
+    let transfer_request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(TRANSFER_AMOUNT_1),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    mint_and_burn(&mut builder, cep18_token);
+
+    let audit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_AUDIT_SUPPLY,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(audit_request).expect_success().commit();
+
+    let audit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_AUDIT_SUPPLY,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(audit_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+}
+
+#[test]
+fn should_not_audit_supply_without_holder_registry() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let audit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_AUDIT_SUPPLY,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(audit_request).commit();
+    assert_user_error(&builder, ERROR_HOLDER_REGISTRY_DISABLED);
+}
@@ -0,0 +1,158 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_OPERATION_ID, ARG_OWNER, CHANGE_SECURITY, ENABLE_MINT_BURN,
+        ERROR_INSUFFICIENT_RIGHTS, ERROR_OPERATION_NOT_PENDING, ERROR_OPERATION_NOT_READY,
+        METHOD_CANCEL_SCHEDULED, METHOD_EXECUTE_SCHEDULED, METHOD_MINT, MINTER_LIST,
+        TIMELOCK_DELAY_SECONDS,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const DELAY_SECONDS: u64 = 3_600;
+const SCHEDULE_TIME: u64 = 1_000;
+const ETA: u64 = SCHEDULE_TIME + DELAY_SECONDS * 1_000;
+const MINT_AMOUNT: u64 = 100;
+
+fn make_request(
+    sender: AccountHash,
+    cep18_token: ContractHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(sender, cep18_token, entry_point, args)
+        .with_block_time(block_time)
+        .build()
+}
+
+/// Schedules making the first test account a minter.
+fn schedule_minter(builder: &mut InMemoryWasmTestBuilder, cep18_token: ContractHash) {
+    let request = make_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! { MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)] },
+        SCHEDULE_TIME,
+    );
+    builder.exec(request).expect_success().commit();
+}
+
+fn make_mint_request(cep18_token: ContractHash, block_time: u64) -> ExecuteRequest {
+    make_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(MINT_AMOUNT),
+        },
+        block_time,
+    )
+}
+
+fn make_operation_request(
+    cep18_token: ContractHash,
+    entry_point: &str,
+    block_time: u64,
+) -> ExecuteRequest {
+    make_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        entry_point,
+        runtime_args! { ARG_OPERATION_ID => 0u64 },
+        block_time,
+    )
+}
+
+#[test]
+fn should_apply_security_change_after_delay() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        TIMELOCK_DELAY_SECONDS => DELAY_SECONDS,
+    });
+    schedule_minter(&mut builder, cep18_token);
+
+    builder
+        .exec(make_mint_request(cep18_token, SCHEDULE_TIME))
+        .commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    builder
+        .exec(make_operation_request(
+            cep18_token,
+            METHOD_EXECUTE_SCHEDULED,
+            ETA - 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_OPERATION_NOT_READY);
+
+    builder
+        .exec(make_operation_request(
+            cep18_token,
+            METHOD_EXECUTE_SCHEDULED,
+            ETA,
+        ))
+        .expect_success()
+        .commit();
+    builder
+        .exec(make_mint_request(cep18_token, ETA))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(MINT_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_execute_cancelled_operation() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        TIMELOCK_DELAY_SECONDS => DELAY_SECONDS,
+    });
+    schedule_minter(&mut builder, cep18_token);
+
+    builder
+        .exec(make_operation_request(
+            cep18_token,
+            METHOD_CANCEL_SCHEDULED,
+            SCHEDULE_TIME,
+        ))
+        .expect_success()
+        .commit();
+    builder
+        .exec(make_operation_request(
+            cep18_token,
+            METHOD_EXECUTE_SCHEDULED,
+            ETA,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_OPERATION_NOT_PENDING);
+}
+
+#[test]
+fn should_not_schedule_without_admin_rights() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        TIMELOCK_DELAY_SECONDS => DELAY_SECONDS,
+    });
+
+    let request = make_request(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CHANGE_SECURITY,
+        runtime_args! { MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)] },
+        SCHEDULE_TIME,
+    );
+    builder.exec(request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+}
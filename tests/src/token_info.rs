@@ -0,0 +1,80 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_2_ADDR, ARG_ICON_URL, ENABLE_MINT_BURN, ENABLE_TRANSFER_FEE, METHOD_SET_METADATA,
+        MINT_WINDOW_LIMIT, MINT_WINDOW_SECONDS, TIMELOCK_DELAY_SECONDS, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY, TRANSFER_FEE_BPS, TRANSFER_FEE_TREASURY,
+    },
+    installer_request_builders::{
+        cep18_check_token_info, setup, setup_with_modalities, TestContext,
+    },
+    token_info::TokenInfo,
+};
+
+const ICON_URL: &str = "https://example.com/icon.svg";
+
+#[test]
+fn should_return_token_info() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    assert_eq!(
+        cep18_check_token_info(&mut builder, &cep18_token),
+        TokenInfo {
+            schema_version: 3,
+            name: TOKEN_NAME.to_string(),
+            symbol: TOKEN_SYMBOL.to_string(),
+            decimals: TOKEN_DECIMALS,
+            total_supply: U256::from(TOKEN_TOTAL_SUPPLY),
+            icon_url: None,
+            website: None,
+            description: None,
+            events_mode: 0,
+            enable_mint_burn: 0,
+            enable_transfer_fee: 0,
+            enable_rebasing: 0,
+            enable_minter_allowances: 0,
+            enable_max_balance: 0,
+            enable_transfer_limits: 0,
+            wrapper_mode: 0,
+            dividends_mode: 0,
+            enable_holder_registry: 0,
+            contract_version: "1.2.0".to_string(),
+            enable_timelock: 0,
+            enable_council: 0,
+            enable_legacy_migration: 0,
+            enable_mint_window: 0,
+        }
+    );
+}
+
+#[test]
+fn should_return_token_info_with_modalities_and_metadata() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => 1u8,
+        ENABLE_TRANSFER_FEE => 1u8,
+        TRANSFER_FEE_BPS => 100u32,
+        TRANSFER_FEE_TREASURY => Key::Account(*ACCOUNT_2_ADDR),
+        MINT_WINDOW_SECONDS => 3_600u64,
+        MINT_WINDOW_LIMIT => U256::from(1_000),
+        TIMELOCK_DELAY_SECONDS => 60u64,
+    });
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_METADATA,
+        runtime_args! { ARG_ICON_URL => ICON_URL },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+
+    let token_info = cep18_check_token_info(&mut builder, &cep18_token);
+    assert_eq!(token_info.icon_url, Some(ICON_URL.to_string()));
+    assert_eq!(token_info.enable_mint_burn, 1);
+    assert_eq!(token_info.enable_transfer_fee, 1);
+    assert_eq!(token_info.enable_rebasing, 0);
+    assert_eq!(token_info.enable_timelock, 1);
+    assert_eq!(token_info.enable_council, 0);
+    assert_eq!(token_info.enable_mint_window, 1);
+}
@@ -0,0 +1,166 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_OWNER, ARG_RECIPIENT, CHANGE_TRANSFER_FEE,
+        ENABLE_TRANSFER_FEE, ERROR_INSUFFICIENT_RIGHTS, ERROR_INVALID_TRANSFER_FEE,
+        ERROR_TRANSFER_FEE_DISABLED, EXEMPT_LIST, METHOD_TRANSFER_FROM, TOKEN_OWNER_ADDRESS_1,
+        TOKEN_TOTAL_SUPPLY, TRANSFER_FEE_BPS, TRANSFER_FEE_TREASURY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, make_cep18_approve_request,
+        make_cep18_transfer_request, setup, setup_with_modalities, TestContext,
+    },
+};
+
+#[test]
+fn should_route_transfer_fee_to_treasury() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_FEE => 1u8,
+        TRANSFER_FEE_BPS => 100u32,
+        TRANSFER_FEE_TREASURY => Key::Account(*ACCOUNT_2_ADDR),
+    });
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+    let treasury = Key::Account(*ACCOUNT_2_ADDR);
+
+    let transfer_request =
+        make_cep18_transfer_request(sender, &cep18_token, recipient, U256::from(10_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, sender),
+        U256::from(TOKEN_TOTAL_SUPPLY) - 10_000
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(9_900)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(100)
+    );
+
+    let approve_request =
+        make_cep18_approve_request(sender, &cep18_token, recipient, U256::from(1_000));
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => sender,
+            ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(1_000),
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(990)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, treasury),
+        U256::from(110)
+    );
+}
+
+#[test]
+fn should_not_charge_fee_to_exempt_accounts() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_FEE => 1u8,
+        TRANSFER_FEE_BPS => 100u32,
+        TRANSFER_FEE_TREASURY => Key::Account(*ACCOUNT_2_ADDR),
+    });
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let change_transfer_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_TRANSFER_FEE,
+        runtime_args! {
+            EXEMPT_LIST => vec![sender],
+        },
+    )
+    .build();
+    builder
+        .exec(change_transfer_fee_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request =
+        make_cep18_transfer_request(sender, &cep18_token, recipient, U256::from(10_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, recipient),
+        U256::from(10_000)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_change_fee_within_bounds() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_FEE => 1u8,
+        TRANSFER_FEE_BPS => 100u32,
+        TRANSFER_FEE_TREASURY => Key::Account(*ACCOUNT_2_ADDR),
+    });
+
+    let non_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        cep18_token,
+        CHANGE_TRANSFER_FEE,
+        runtime_args! {
+            TRANSFER_FEE_BPS => 0u32,
+        },
+    )
+    .build();
+    builder.exec(non_admin_request).commit();
+
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RIGHTS);
+
+    let out_of_bounds_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_TRANSFER_FEE,
+        runtime_args! {
+            TRANSFER_FEE_BPS => 1_001u32,
+        },
+    )
+    .build();
+    builder.exec(out_of_bounds_request).commit();
+
+    assert_user_error(&builder, ERROR_INVALID_TRANSFER_FEE);
+}
+
+#[test]
+fn should_not_change_fee_with_fee_mode_disabled() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    let change_transfer_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        CHANGE_TRANSFER_FEE,
+        runtime_args! {
+            TRANSFER_FEE_BPS => 100u32,
+        },
+    )
+    .build();
+    builder.exec(change_transfer_fee_request).commit();
+
+    assert_user_error(&builder, ERROR_TRANSFER_FEE_DISABLED);
+}
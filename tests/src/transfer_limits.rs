@@ -0,0 +1,118 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_RECIPIENT, DAILY_TRANSFER_LIMIT,
+        ENABLE_TRANSFER_LIMITS, ERROR_DAILY_TRANSFER_LIMIT_EXCEEDED, METHOD_SET_TRANSFER_LIMIT,
+        METHOD_TRANSFER,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_remaining_daily_limit, setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const DAILY_LIMIT: u64 = 1_000;
+const DAY_MILLIS: u64 = 86_400_000;
+
+fn make_transfer_request(
+    cep18_token: ContractHash,
+    amount: u64,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(amount),
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_limit_transfers_per_day() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_LIMITS => 1u8,
+        DAILY_TRANSFER_LIMIT => U256::from(DAILY_LIMIT),
+    });
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    builder
+        .exec(make_transfer_request(cep18_token, 600, 0))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_remaining_daily_limit(&mut builder, &cep18_token, sender),
+        U256::from(400)
+    );
+
+    builder
+        .exec(make_transfer_request(cep18_token, 500, 1_000))
+        .commit();
+    assert_user_error(&builder, ERROR_DAILY_TRANSFER_LIMIT_EXCEEDED);
+
+    // The whole limit is available again on the next day.
+    builder
+        .exec(make_transfer_request(cep18_token, DAILY_LIMIT, DAY_MILLIS))
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_apply_transfer_limit_overrides() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_LIMITS => 1u8,
+        DAILY_TRANSFER_LIMIT => U256::from(DAILY_LIMIT),
+    });
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let set_transfer_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_TRANSFER_LIMIT,
+        runtime_args! {
+            ARG_ADDRESS => sender,
+            DAILY_TRANSFER_LIMIT => Some(U256::from(5_000)),
+        },
+    )
+    .build();
+    builder
+        .exec(set_transfer_limit_request)
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_transfer_request(cep18_token, 3_000, 0))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_remaining_daily_limit(&mut builder, &cep18_token, sender),
+        U256::from(2_000)
+    );
+
+    // Once the override is removed the default limit applies again.
+    let remove_transfer_limit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_SET_TRANSFER_LIMIT,
+        runtime_args! {
+            ARG_ADDRESS => sender,
+            DAILY_TRANSFER_LIMIT => None::<U256>,
+        },
+    )
+    .build();
+    builder
+        .exec(remove_transfer_limit_request)
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_remaining_daily_limit(&mut builder, &cep18_token, sender),
+        U256::zero()
+    );
+}
@@ -0,0 +1,250 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_1_SECRET_KEY, ACCOUNT_2_ADDR,
+        ACCOUNT_2_PUBLIC_KEY, ACCOUNT_2_SECRET_KEY, ARG_AMOUNT, ARG_FROM, ARG_NONCE, ARG_SIGNATURE,
+        ARG_SIGNER_PUBLIC_KEY, ARG_TO, ARG_VALID_AFTER, ARG_VALID_BEFORE,
+        ERROR_AUTHORIZATION_ALREADY_USED, ERROR_AUTHORIZATION_EXPIRED,
+        ERROR_AUTHORIZATION_NOT_YET_VALID, ERROR_INVALID_AUTHORIZATION_SIGNATURE,
+        METHOD_CANCEL_AUTHORIZATION, METHOD_TRANSFER_WITH_AUTHORIZATION,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, get_cep18_package_key,
+        make_cep18_transfer_request, setup, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const AUTHORIZED_AMOUNT: u64 = 1_000;
+const VALID_AFTER: u64 = 1_000;
+const VALID_BEFORE: u64 = 10_000;
+
+fn setup_authorizations() -> (InMemoryWasmTestBuilder, TestContext) {
+    let (mut builder, test_context) = setup();
+    let request = make_cep18_transfer_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &test_context.cep18_token,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::from(AUTHORIZED_AMOUNT),
+    );
+    builder.exec(request).expect_success().commit();
+    (builder, test_context)
+}
+
+fn sign(secret_key: &SecretKey, public_key: &PublicKey, bytes: Vec<u8>) -> Bytes {
+    let signature = crypto::sign(crypto::blake2b(bytes), secret_key, public_key);
+    Bytes::from(signature.to_bytes().unwrap())
+}
+
+fn sign_transfer(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    secret_key: &SecretKey,
+    public_key: &PublicKey,
+    nonce: [u8; 32],
+) -> Bytes {
+    let bytes = (
+        get_cep18_package_key(builder, cep18_token),
+        METHOD_TRANSFER_WITH_AUTHORIZATION,
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(AUTHORIZED_AMOUNT),
+        VALID_AFTER,
+        VALID_BEFORE,
+        nonce,
+    )
+        .to_bytes()
+        .unwrap();
+    sign(secret_key, public_key, bytes)
+}
+
+fn make_transfer_request(
+    cep18_token: ContractHash,
+    signer_public_key: &PublicKey,
+    signature: Bytes,
+    nonce: [u8; 32],
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_TRANSFER_WITH_AUTHORIZATION,
+        runtime_args! {
+            ARG_FROM => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_TO => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::from(AUTHORIZED_AMOUNT),
+            ARG_VALID_AFTER => VALID_AFTER,
+            ARG_VALID_BEFORE => VALID_BEFORE,
+            ARG_NONCE => nonce,
+            ARG_SIGNER_PUBLIC_KEY => signer_public_key.clone(),
+            ARG_SIGNATURE => signature,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_transfer_with_authorization_once() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_authorizations();
+    let nonce = [1u8; 32];
+    let signature = sign_transfer(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        nonce,
+    );
+
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature.clone(),
+            nonce,
+            VALID_AFTER + 1,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(AUTHORIZED_AMOUNT)
+    );
+
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            nonce,
+            VALID_AFTER + 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_AUTHORIZATION_ALREADY_USED);
+}
+
+#[test]
+fn should_not_transfer_with_invalid_authorization() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_authorizations();
+    let nonce = [1u8; 32];
+
+    let signature = sign_transfer(
+        &builder,
+        cep18_token,
+        &ACCOUNT_2_SECRET_KEY,
+        &ACCOUNT_2_PUBLIC_KEY,
+        nonce,
+    );
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_2_PUBLIC_KEY,
+            signature.clone(),
+            nonce,
+            VALID_AFTER + 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_AUTHORIZATION_SIGNATURE);
+
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            nonce,
+            VALID_AFTER + 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_AUTHORIZATION_SIGNATURE);
+
+    let signature = sign_transfer(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        nonce,
+    );
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature.clone(),
+            nonce,
+            VALID_AFTER,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_AUTHORIZATION_NOT_YET_VALID);
+
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            nonce,
+            VALID_BEFORE,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_AUTHORIZATION_EXPIRED);
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(AUTHORIZED_AMOUNT)
+    );
+}
+
+#[test]
+fn should_cancel_authorization() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_authorizations();
+    let nonce = [1u8; 32];
+    let bytes = (
+        get_cep18_package_key(&builder, cep18_token),
+        METHOD_CANCEL_AUTHORIZATION,
+        Key::Account(*ACCOUNT_1_ADDR),
+        nonce,
+    )
+        .to_bytes()
+        .unwrap();
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_CANCEL_AUTHORIZATION,
+        runtime_args! {
+            ARG_FROM => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_NONCE => nonce,
+            ARG_SIGNER_PUBLIC_KEY => ACCOUNT_1_PUBLIC_KEY.clone(),
+            ARG_SIGNATURE => sign(&ACCOUNT_1_SECRET_KEY, &ACCOUNT_1_PUBLIC_KEY, bytes),
+        },
+    )
+    .build();
+    builder.exec(cancel_request).expect_success().commit();
+
+    let signature = sign_transfer(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        nonce,
+    );
+    builder
+        .exec(make_transfer_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            nonce,
+            VALID_AFTER + 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_AUTHORIZATION_ALREADY_USED);
+}
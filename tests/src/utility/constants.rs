@@ -39,8 +39,22 @@ pub const ARG_SPENDER: &str = "spender";
 pub const METHOD_TRANSFER_FROM: &str = "transfer_from";
 
 pub const CHECK_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_total_supply";
+pub const CHECK_TOTAL_MINTED_ENTRYPOINT: &str = "check_total_minted";
+pub const CHECK_TOTAL_BURNED_ENTRYPOINT: &str = "check_total_burned";
 pub const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
 pub const CHECK_ALLOWANCE_OF_ENTRYPOINT: &str = "check_allowance_of";
+pub const CHECK_MINTER_ALLOWANCE_ENTRYPOINT: &str = "check_minter_allowance";
+pub const CHECK_REMAINING_DAILY_LIMIT_ENTRYPOINT: &str = "check_remaining_daily_limit";
+pub const CHECK_BALANCE_ON_HOLD_ENTRYPOINT: &str = "check_balance_on_hold";
+pub const CHECK_STREAM_BALANCE_ENTRYPOINT: &str = "check_stream_balance";
+pub const CHECK_WITHDRAWABLE_DIVIDEND_OF_ENTRYPOINT: &str = "check_withdrawable_dividend_of";
+pub const CHECK_CONVERT_TO_ASSETS_ENTRYPOINT: &str = "check_convert_to_assets";
+pub const CHECK_TOKEN_METADATA_ENTRYPOINT: &str = "check_token_metadata";
+pub const CHECK_TOKEN_INFO_ENTRYPOINT: &str = "check_token_info";
+pub const CHECK_BALANCES_OF_ENTRYPOINT: &str = "check_balances_of";
+pub const CHECK_ALLOWANCES_OF_ENTRYPOINT: &str = "check_allowances_of";
+pub const CHECK_HOLDER_COUNT_ENTRYPOINT: &str = "check_holder_count";
+pub const CHECK_HOLDERS_ENTRYPOINT: &str = "check_holders";
 pub const ARG_TOKEN_CONTRACT: &str = "token_contract";
 pub const ARG_ADDRESS: &str = "address";
 pub const RESULT_KEY: &str = "result";
@@ -81,3 +95,187 @@ pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const NONE_LIST: &str = "none_list";
 pub const CHANGE_SECURITY: &str = "change_security";
+pub const EVENTS_MODE: &str = "events_mode";
+pub const EVENTS_KEY: &str = "__events";
+pub const EVENTS_LENGTH_KEY: &str = "__events_length";
+
+pub const ENABLE_TRANSFER_FEE: &str = "enable_transfer_fee";
+pub const TRANSFER_FEE_BPS: &str = "transfer_fee_bps";
+pub const TRANSFER_FEE_TREASURY: &str = "transfer_fee_treasury";
+pub const CHANGE_TRANSFER_FEE: &str = "change_transfer_fee";
+pub const EXEMPT_LIST: &str = "exempt_list";
+
+pub const ERROR_INSUFFICIENT_RIGHTS: u16 = 60010;
+pub const ERROR_INVALID_ADMIN_LIST: u16 = 60011;
+pub const ERROR_INVALID_TRANSFER_FEE: u16 = 60021;
+pub const ERROR_TRANSFER_FEE_DISABLED: u16 = 60023;
+
+pub const ENABLE_REBASING: &str = "enable_rebasing";
+pub const REBASER_LIST: &str = "rebaser_list";
+pub const METHOD_REBASE: &str = "rebase";
+pub const ARG_NEW_TOTAL_SUPPLY: &str = "new_total_supply";
+
+pub const WRAPPER_MODE: &str = "wrapper_mode";
+pub const WRAPPER_PURSE: &str = "wrapper_purse";
+pub const METHOD_DEPOSIT_AS_STORED_CONTRACT: &str = "deposit_as_stored_contract";
+pub const TEST_CONTRACT_PURSE_KEY: &str = "purse";
+pub const METHOD_WITHDRAW: &str = "withdraw";
+pub const ERROR_WRAPPER_DISABLED: u16 = 60031;
+
+pub const BRIDGE_LIST: &str = "bridge_list";
+pub const METHOD_BRIDGE_MINT: &str = "bridge_mint";
+pub const METHOD_BRIDGE_BURN: &str = "bridge_burn";
+pub const ARG_EXTERNAL_REF: &str = "external_ref";
+pub const ARG_DESTINATION_CHAIN: &str = "destination_chain";
+pub const ARG_DESTINATION_ADDRESS: &str = "destination_address";
+pub const ERROR_EXTERNAL_REF_ALREADY_PROCESSED: u16 = 60033;
+
+pub const ENABLE_MINTER_ALLOWANCES: &str = "enable_minter_allowances";
+pub const METHOD_CONFIGURE_MINTER: &str = "configure_minter";
+pub const ARG_MINTER: &str = "minter";
+pub const ARG_ALLOWANCE: &str = "allowance";
+pub const ERROR_MINTER_ALLOWANCES_DISABLED: u16 = 60036;
+pub const ERROR_MINTER_ALLOWANCE_EXCEEDED: u16 = 60037;
+
+pub const MINT_WINDOW_SECONDS: &str = "mint_window_seconds";
+pub const MINT_WINDOW_LIMIT: &str = "mint_window_limit";
+pub const METHOD_CHANGE_MINT_WINDOW: &str = "change_mint_window";
+pub const ERROR_MINT_WINDOW_LIMIT_EXCEEDED: u16 = 60039;
+
+pub const ENABLE_TRANSFER_LIMITS: &str = "enable_transfer_limits";
+pub const DAILY_TRANSFER_LIMIT: &str = "daily_transfer_limit";
+pub const METHOD_SET_TRANSFER_LIMIT: &str = "set_transfer_limit";
+pub const ERROR_DAILY_TRANSFER_LIMIT_EXCEEDED: u16 = 60042;
+
+pub const ENABLE_MAX_BALANCE: &str = "enable_max_balance";
+pub const MAX_BALANCE: &str = "max_balance";
+pub const METHOD_CHANGE_MAX_BALANCE: &str = "change_max_balance";
+pub const ERROR_MAX_BALANCE_DISABLED: u16 = 60045;
+pub const ERROR_MAX_BALANCE_EXCEEDED: u16 = 60047;
+
+pub const METHOD_HOLD: &str = "hold";
+pub const METHOD_EXECUTE_HOLD: &str = "execute_hold";
+pub const METHOD_RELEASE_HOLD: &str = "release_hold";
+pub const ARG_HOLD_ID: &str = "hold_id";
+pub const ARG_NOTARY: &str = "notary";
+pub const ARG_EXPIRATION: &str = "expiration";
+pub const ERROR_HOLD_ALREADY_EXISTS: u16 = 60048;
+pub const ERROR_HOLD_NOT_ACTIVE: u16 = 60050;
+pub const ERROR_HOLD_NOT_EXPIRED: u16 = 60052;
+
+pub const METHOD_TRANSFER_WITH_MEMO: &str = "transfer_with_memo";
+pub const METHOD_TRANSFER_FROM_WITH_MEMO: &str = "transfer_from_with_memo";
+pub const ARG_MEMO: &str = "memo";
+pub const ERROR_INVALID_MEMO: u16 = 60053;
+
+pub const METHOD_CREATE_STREAM: &str = "create_stream";
+pub const METHOD_WITHDRAW_FROM_STREAM: &str = "withdraw_from_stream";
+pub const METHOD_CANCEL_STREAM: &str = "cancel_stream";
+pub const ARG_STREAM_ID: &str = "stream_id";
+pub const ARG_START_TIME: &str = "start_time";
+pub const ARG_END_TIME: &str = "end_time";
+pub const ERROR_INVALID_STREAM: u16 = 60055;
+pub const ERROR_STREAM_NOT_ACTIVE: u16 = 60056;
+
+pub const DIVIDENDS_MODE: &str = "dividends_mode";
+pub const DIVIDEND_TOKEN: &str = "dividend_token";
+pub const METHOD_DISTRIBUTE: &str = "distribute";
+pub const METHOD_CLAIM_DIVIDEND: &str = "claim_dividend";
+pub const ERROR_DIVIDENDS_DISABLED: u16 = 60058;
+pub const ERROR_NO_DIVIDEND_TO_CLAIM: u16 = 60061;
+
+pub const UNDERLYING_TOKEN: &str = "underlying_token";
+pub const METHOD_DEPOSIT: &str = "deposit";
+pub const METHOD_MINT_SHARES: &str = "mint_shares";
+pub const METHOD_REDEEM: &str = "redeem";
+pub const ARG_SHARES: &str = "shares";
+pub const ERROR_INVALID_VAULT_AMOUNT: u16 = 60063;
+
+pub const METHOD_WRAP: &str = "wrap";
+pub const METHOD_UNWRAP: &str = "unwrap";
+pub const ERROR_WRAPPER_INVARIANT_VIOLATED: u16 = 60064;
+
+pub const LEGACY_TOKEN: &str = "legacy_token";
+pub const LEGACY_RATIO_NUMERATOR: &str = "legacy_ratio_numerator";
+pub const LEGACY_RATIO_DENOMINATOR: &str = "legacy_ratio_denominator";
+pub const METHOD_MIGRATE_FROM_LEGACY: &str = "migrate_from_legacy";
+pub const METHOD_CLOSE_LEGACY_MIGRATION: &str = "close_legacy_migration";
+pub const ERROR_LEGACY_MIGRATION_DISABLED: u16 = 60066;
+pub const ERROR_LEGACY_MIGRATION_CLOSED: u16 = 60067;
+pub const ERROR_INVALID_LEGACY_RATIO: u16 = 60068;
+
+pub const COUNCIL: &str = "council";
+pub const COUNCIL_THRESHOLD: &str = "council_threshold";
+pub const METHOD_CREATE_PROPOSAL: &str = "create_proposal";
+pub const METHOD_APPROVE_PROPOSAL: &str = "approve_proposal";
+pub const METHOD_EXECUTE_PROPOSAL: &str = "execute_proposal";
+pub const METHOD_CHANGE_COUNCIL: &str = "change_council";
+pub const ARG_ACTION: &str = "action";
+pub const ARG_ARGS: &str = "args";
+pub const ARG_PROPOSAL_ID: &str = "proposal_id";
+pub const ERROR_INVALID_COUNCIL_THRESHOLD: u16 = 60071;
+pub const ERROR_NOT_COUNCIL_MEMBER: u16 = 60073;
+pub const ERROR_PROPOSAL_ALREADY_EXECUTED: u16 = 60076;
+pub const ERROR_PROPOSAL_THRESHOLD_NOT_MET: u16 = 60077;
+pub const ERROR_INVALID_PROPOSAL: u16 = 60078;
+pub const ERROR_AIRDROP_ESCROW_FROM_SELF: u16 = 60101;
+pub const ERROR_INVALID_WRAPPER_SUPPLY: u16 = 60102;
+pub const ERROR_STREAM_WITH_REBASING: u16 = 60103;
+pub const ERROR_AIRDROP_WITH_REBASING: u16 = 60104;
+
+pub const TIMELOCK_DELAY_SECONDS: &str = "timelock_delay_seconds";
+pub const METHOD_EXECUTE_SCHEDULED: &str = "execute_scheduled";
+pub const METHOD_CANCEL_SCHEDULED: &str = "cancel_scheduled";
+pub const ARG_OPERATION_ID: &str = "operation_id";
+pub const ERROR_OPERATION_NOT_PENDING: u16 = 60082;
+pub const ERROR_OPERATION_NOT_READY: u16 = 60083;
+
+pub const METHOD_SET_MERKLE_ROOT: &str = "set_merkle_root";
+pub const METHOD_CLAIM: &str = "claim";
+pub const ARG_ROOT: &str = "root";
+pub const ARG_TOTAL: &str = "total";
+pub const ARG_INDEX: &str = "index";
+pub const ARG_ACCOUNT: &str = "account";
+pub const ARG_PROOF: &str = "proof";
+pub const ERROR_INVALID_MERKLE_PROOF: u16 = 60085;
+pub const ERROR_AIRDROP_ALREADY_CLAIMED: u16 = 60086;
+
+pub const METHOD_MINT_WITH_VOUCHER: &str = "mint_with_voucher";
+pub const ARG_NONCE: &str = "nonce";
+pub const ARG_EXPIRY: &str = "expiry";
+pub const ARG_SIGNER_PUBLIC_KEY: &str = "signer_public_key";
+pub const ARG_SIGNATURE: &str = "signature";
+pub const ERROR_VOUCHER_EXPIRED: u16 = 60088;
+pub const ERROR_INVALID_VOUCHER_SIGNER: u16 = 60089;
+pub const ERROR_INVALID_VOUCHER_SIGNATURE: u16 = 60090;
+pub const ERROR_VOUCHER_ALREADY_REDEEMED: u16 = 60091;
+
+pub const METHOD_TRANSFER_WITH_AUTHORIZATION: &str = "transfer_with_authorization";
+pub const METHOD_CANCEL_AUTHORIZATION: &str = "cancel_authorization";
+pub const ARG_FROM: &str = "from";
+pub const ARG_TO: &str = "to";
+pub const ARG_VALID_AFTER: &str = "valid_after";
+pub const ARG_VALID_BEFORE: &str = "valid_before";
+pub const ERROR_AUTHORIZATION_NOT_YET_VALID: u16 = 60092;
+pub const ERROR_AUTHORIZATION_EXPIRED: u16 = 60093;
+pub const ERROR_INVALID_AUTHORIZATION_SIGNATURE: u16 = 60094;
+pub const ERROR_AUTHORIZATION_ALREADY_USED: u16 = 60095;
+
+pub const METHOD_SET_METADATA: &str = "set_metadata";
+pub const ARG_ICON_URL: &str = "icon_url";
+pub const ARG_WEBSITE: &str = "website";
+pub const ARG_DESCRIPTION: &str = "description";
+pub const ERROR_INVALID_METADATA: u16 = 60096;
+
+pub const METHOD_BALANCES_OF: &str = "balances_of";
+pub const ARG_ADDRESSES: &str = "addresses";
+pub const ARG_PAIRS: &str = "pairs";
+pub const MAX_BATCH_LENGTH: usize = 100;
+pub const ERROR_BATCH_TOO_LARGE: u16 = 60097;
+pub const ENABLE_HOLDER_REGISTRY: &str = "enable_holder_registry";
+pub const METHOD_HOLDER_COUNT: &str = "holder_count";
+pub const METHOD_HOLDERS: &str = "holders";
+pub const ARG_START: &str = "start";
+pub const ARG_LIMIT: &str = "limit";
+pub const ERROR_HOLDER_REGISTRY_DISABLED: u16 = 60099;
+pub const METHOD_AUDIT_SUPPLY: &str = "audit_supply";
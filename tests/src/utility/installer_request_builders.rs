@@ -1,25 +1,43 @@
+use std::collections::BTreeMap;
+
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, runtime_args, system::mint, CLTyped, ContractHash,
-    ContractPackageHash, Key, RuntimeArgs, U256,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes},
+    runtime_args,
+    system::mint,
+    ApiError, CLTyped, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 
-use crate::utility::constants::{
-    ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1, TRANSFER_AMOUNT_2,
+use crate::utility::{
+    constants::{
+        ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, TOTAL_SUPPLY_KEY, TRANSFER_AMOUNT_1,
+        TRANSFER_AMOUNT_2,
+    },
+    token_info::TokenInfo,
 };
 
 use super::constants::{
-    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER,
-    ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
+    ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_ADDRESS, ARG_ADDRESSES, ARG_AMOUNT, ARG_DECIMALS,
+    ARG_LIMIT, ARG_MINTER, ARG_NAME, ARG_OWNER, ARG_PAIRS, ARG_RECIPIENT, ARG_SHARES, ARG_SPENDER,
+    ARG_START, ARG_STREAM_ID, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
     CEP18_CONTRACT_WASM, CEP18_TEST_CONTRACT_KEY, CEP18_TEST_CONTRACT_WASM,
-    CEP18_TOKEN_CONTRACT_KEY, CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
-    CHECK_TOTAL_SUPPLY_ENTRYPOINT, METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT,
-    METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT, RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME,
-    TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    CEP18_TOKEN_CONTRACT_KEY, CHECK_ALLOWANCES_OF_ENTRYPOINT, CHECK_ALLOWANCE_OF_ENTRYPOINT,
+    CHECK_BALANCES_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT, CHECK_BALANCE_ON_HOLD_ENTRYPOINT,
+    CHECK_CONVERT_TO_ASSETS_ENTRYPOINT, CHECK_HOLDERS_ENTRYPOINT, CHECK_HOLDER_COUNT_ENTRYPOINT,
+    CHECK_MINTER_ALLOWANCE_ENTRYPOINT, CHECK_REMAINING_DAILY_LIMIT_ENTRYPOINT,
+    CHECK_STREAM_BALANCE_ENTRYPOINT, CHECK_TOKEN_INFO_ENTRYPOINT, CHECK_TOKEN_METADATA_ENTRYPOINT,
+    CHECK_TOTAL_BURNED_ENTRYPOINT, CHECK_TOTAL_MINTED_ENTRYPOINT, CHECK_TOTAL_SUPPLY_ENTRYPOINT,
+    CHECK_WITHDRAWABLE_DIVIDEND_OF_ENTRYPOINT, EVENTS_KEY, EVENTS_LENGTH_KEY, METHOD_APPROVE,
+    METHOD_APPROVE_AS_STORED_CONTRACT, METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT,
+    RESULT_KEY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
 };
 
 /// Converts hash addr of Account into Hash, and Hash into Account
@@ -49,6 +67,25 @@ pub(crate) fn setup() -> (InMemoryWasmTestBuilder, TestContext) {
     })
 }
 
+/// Installs the token with the default name, symbol, decimals and total supply, together with
+/// `modality_args`. Any of the defaults can be overridden by passing it in `modality_args`.
+pub(crate) fn setup_with_modalities(
+    mut modality_args: RuntimeArgs,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let defaults = runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+    };
+    for named_arg in defaults.named_args() {
+        if modality_args.get(named_arg.name()).is_none() {
+            modality_args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+        }
+    }
+    setup_with_args(modality_args)
+}
+
 pub(crate) fn setup_with_args(install_args: RuntimeArgs) -> (InMemoryWasmTestBuilder, TestContext) {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
@@ -112,6 +149,83 @@ pub(crate) fn setup_with_args(install_args: RuntimeArgs) -> (InMemoryWasmTestBui
     (builder, test_context)
 }
 
+/// Installs another CEP-18 token next to the one of the test setup and returns its contract hash.
+/// The install arguments must include the `name` of the token.
+pub(crate) fn install_cep18_token(
+    builder: &mut InMemoryWasmTestBuilder,
+    install_args: RuntimeArgs,
+) -> ContractHash {
+    let name: String = install_args
+        .get(ARG_NAME)
+        .cloned()
+        .and_then(|name| name.into_t().ok())
+        .expect("should have token name");
+    let install_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CEP18_CONTRACT_WASM, install_args)
+            .build();
+    builder.exec(install_request).expect_success().commit();
+
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(&format!("cep18_contract_hash_{name}"))
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash")
+}
+
+/// Returns the contract package hash of a token, under which it holds its own escrowed tokens and
+/// calls other contracts.
+pub(crate) fn get_cep18_package_key(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+) -> Key {
+    builder
+        .get_contract(cep18_token)
+        .expect("should have contract")
+        .contract_package_hash()
+        .into()
+}
+
+/// Returns the bytes of the last event the token recorded in its `__events` dictionary.
+pub(crate) fn get_last_event(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+) -> Bytes {
+    let contract = builder
+        .get_contract(cep18_token)
+        .expect("should have contract");
+    let named_uref = |name: &str| {
+        contract
+            .named_keys()
+            .get(name)
+            .and_then(Key::as_uref)
+            .copied()
+            .expect("should have events key")
+    };
+    let events_length: u32 = builder
+        .query(None, Key::URef(named_uref(EVENTS_LENGTH_KEY)), &[])
+        .expect("should have events length")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be u32");
+    builder
+        .query_dictionary_item(
+            None,
+            named_uref(EVENTS_KEY),
+            &(events_length - 1).to_string(),
+        )
+        .expect("should have event")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be event bytes")
+}
+
 pub(crate) fn cep18_check_total_supply(
     builder: &mut InMemoryWasmTestBuilder,
     cep18_contract_hash: &ContractHash,
@@ -144,6 +258,80 @@ pub(crate) fn cep18_check_total_supply(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_total_minted(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_total_minted_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_TOTAL_MINTED_ENTRYPOINT,
+        check_total_minted_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_total_burned(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_total_burned_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_TOTAL_BURNED_ENTRYPOINT,
+        check_total_burned_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+/// Asserts that the last execution reverted with the user error `expected`.
+pub(crate) fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
 pub(crate) fn get_test_result<T: FromBytes + CLTyped>(
     builder: &mut InMemoryWasmTestBuilder,
     cep18_test_contract_package: ContractPackageHash,
@@ -232,6 +420,400 @@ pub(crate) fn cep18_check_allowance_of(
     get_test_result(builder, cep18_test_contract_package)
 }
 
+pub(crate) fn cep18_check_minter_allowance(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    minter: Key,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_minter_allowance_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_MINTER => minter,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_MINTER_ALLOWANCE_ENTRYPOINT,
+        check_minter_allowance_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_remaining_daily_limit(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_remaining_daily_limit_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_REMAINING_DAILY_LIMIT_ENTRYPOINT,
+        check_remaining_daily_limit_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_balance_on_hold(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_balance_on_hold_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BALANCE_ON_HOLD_ENTRYPOINT,
+        check_balance_on_hold_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_stream_balance(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    stream_id: u64,
+    block_time: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_stream_balance_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_STREAM_ID => stream_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_STREAM_BALANCE_ENTRYPOINT,
+        check_stream_balance_args,
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_withdrawable_dividend_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    address: Key,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_withdrawable_dividend_of_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESS => address,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_WITHDRAWABLE_DIVIDEND_OF_ENTRYPOINT,
+        check_withdrawable_dividend_of_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_convert_to_assets(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    shares: U256,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_convert_to_assets_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_SHARES => shares,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_CONVERT_TO_ASSETS_ENTRYPOINT,
+        check_convert_to_assets_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_token_metadata(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> BTreeMap<String, String> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_token_metadata_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_TOKEN_METADATA_ENTRYPOINT,
+        check_token_metadata_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_token_info(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> TokenInfo {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_token_info_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_TOKEN_INFO_ENTRYPOINT,
+        check_token_info_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_balances_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    addresses: Vec<Key>,
+) -> Vec<U256> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_balances_of_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_ADDRESSES => addresses,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_BALANCES_OF_ENTRYPOINT,
+        check_balances_of_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_allowances_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    pairs: Vec<(Key, Key)>,
+) -> Vec<U256> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_allowances_of_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_PAIRS => pairs,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_ALLOWANCES_OF_ENTRYPOINT,
+        check_allowances_of_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_holder_count(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+) -> u64 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_holder_count_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_HOLDER_COUNT_ENTRYPOINT,
+        check_holder_count_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
+pub(crate) fn cep18_check_holders(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_contract_hash: &ContractHash,
+    start: u64,
+    limit: u64,
+) -> Vec<Key> {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let cep18_test_contract_package = account
+        .named_keys()
+        .get(CEP18_TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_holders_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => Key::from(*cep18_contract_hash),
+        ARG_START => start,
+        ARG_LIMIT => limit,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        CHECK_HOLDERS_ENTRYPOINT,
+        check_holders_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, cep18_test_contract_package)
+}
+
 pub(crate) fn test_cep18_transfer(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,
@@ -0,0 +1,66 @@
+//! Host-side construction of the Merkle trees verified by the `claim` entry point.
+use casper_types::{bytesrepr::ToBytes, crypto::blake2b, Key, U256};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+fn hash_leaf(index: u64, account: Key, amount: U256) -> [u8; 32] {
+    let mut bytes = vec![LEAF_PREFIX];
+    bytes.extend((index, account, amount).to_bytes().unwrap());
+    blake2b(bytes)
+}
+
+fn hash_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut bytes = vec![NODE_PREFIX];
+    bytes.extend_from_slice(&left);
+    bytes.extend_from_slice(&right);
+    blake2b(bytes)
+}
+
+/// A Merkle tree over airdrop leaves, where leaf `i` pays `amounts[i]` to `accounts[i]`. A node
+/// without a sibling is promoted to the next level unchanged.
+pub(crate) struct MerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub(crate) fn new(leaves: &[(Key, U256)]) -> Self {
+        let mut levels = vec![leaves
+            .iter()
+            .enumerate()
+            .map(|(index, (account, amount))| hash_leaf(index as u64, *account, *amount))
+            .collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(*left, *right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        MerkleTree { levels }
+    }
+
+    pub(crate) fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the proof of leaf `index`, from the leaf level up.
+    pub(crate) fn proof(&self, index: u64) -> Vec<[u8; 32]> {
+        let mut position = index as usize;
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                proof.push(*sibling);
+            }
+            position /= 2;
+        }
+        proof
+    }
+}
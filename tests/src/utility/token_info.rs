@@ -0,0 +1,103 @@
+//! Host-side deserialization of the value returned by the `token_info` entry point.
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLType, CLTyped, U256,
+};
+
+/// The CL type and serialization of [`TokenInfo`], with the fields grouped into nested tuples.
+type TokenInfoLayout = (
+    u32,
+    (
+        (String, String, u8),
+        U256,
+        (Option<String>, Option<String>, Option<String>),
+    ),
+    (
+        ((u8, u8, u8), (u8, u8, u8), (u8, u8, u8)),
+        (u8, String),
+        ((u8, u8), (u8, u8)),
+    ),
+);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub schema_version: u32,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: U256,
+    pub icon_url: Option<String>,
+    pub website: Option<String>,
+    pub description: Option<String>,
+    pub events_mode: u8,
+    pub enable_mint_burn: u8,
+    pub enable_transfer_fee: u8,
+    pub enable_rebasing: u8,
+    pub enable_minter_allowances: u8,
+    pub enable_max_balance: u8,
+    pub enable_transfer_limits: u8,
+    pub wrapper_mode: u8,
+    pub dividends_mode: u8,
+    pub enable_holder_registry: u8,
+    pub contract_version: String,
+    pub enable_timelock: u8,
+    pub enable_council: u8,
+    pub enable_legacy_migration: u8,
+    pub enable_mint_window: u8,
+}
+
+impl CLTyped for TokenInfo {
+    fn cl_type() -> CLType {
+        TokenInfoLayout::cl_type()
+    }
+}
+
+impl FromBytes for TokenInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (
+            (
+                schema_version,
+                ((name, symbol, decimals), total_supply, (icon_url, website, description)),
+                (
+                    (
+                        (events_mode, enable_mint_burn, enable_transfer_fee),
+                        (enable_rebasing, enable_minter_allowances, enable_max_balance),
+                        (enable_transfer_limits, wrapper_mode, dividends_mode),
+                    ),
+                    (enable_holder_registry, contract_version),
+                    (
+                        (enable_timelock, enable_council),
+                        (enable_legacy_migration, enable_mint_window),
+                    ),
+                ),
+            ),
+            remainder,
+        ) = TokenInfoLayout::from_bytes(bytes)?;
+        let token_info = TokenInfo {
+            schema_version,
+            name,
+            symbol,
+            decimals,
+            total_supply,
+            icon_url,
+            website,
+            description,
+            events_mode,
+            enable_mint_burn,
+            enable_transfer_fee,
+            enable_rebasing,
+            enable_minter_allowances,
+            enable_max_balance,
+            enable_transfer_limits,
+            wrapper_mode,
+            dividends_mode,
+            enable_holder_registry,
+            contract_version,
+            enable_timelock,
+            enable_council,
+            enable_legacy_migration,
+            enable_mint_window,
+        };
+        Ok((token_info, remainder))
+    }
+}
@@ -0,0 +1,228 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_SHARES, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, CEP18_CONTRACT_WASM, ENABLE_TRANSFER_FEE, ERROR_INVALID_VAULT_AMOUNT,
+        ERROR_INVALID_WRAPPER_SUPPLY, ERROR_WRAPPER_DISABLED, METHOD_DEPOSIT, METHOD_MINT_SHARES,
+        METHOD_REDEEM, METHOD_WITHDRAW, TOKEN_DECIMALS, TOKEN_TOTAL_SUPPLY, TRANSFER_FEE_BPS,
+        TRANSFER_FEE_TREASURY, UNDERLYING_TOKEN, WRAPPER_MODE,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_convert_to_assets,
+        cep18_check_total_supply, get_cep18_package_key, install_cep18_token,
+        make_cep18_approve_request, make_cep18_transfer_request, setup, setup_with_modalities,
+        TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const VAULT_TOKEN_NAME: &str = "VaultTest";
+const DEPOSIT_AMOUNT: u64 = 1_000;
+
+/// Installs a vault over the `underlying` token, and approves the vault to take the underlying
+/// tokens of the default account.
+fn install_vault(builder: &mut InMemoryWasmTestBuilder, underlying: ContractHash) -> ContractHash {
+    let underlying_package = get_cep18_package_key(builder, underlying);
+
+    let vault = install_cep18_token(
+        builder,
+        runtime_args! {
+            ARG_NAME => VAULT_TOKEN_NAME,
+            ARG_SYMBOL => "VLT",
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::zero(),
+            WRAPPER_MODE => 2u8,
+            UNDERLYING_TOKEN => underlying_package,
+        },
+    );
+    let approve_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &underlying,
+        get_cep18_package_key(builder, vault),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+    builder.exec(approve_request).expect_success().commit();
+    vault
+}
+
+/// Installs a vault over the token of the default test setup.
+fn setup_vault() -> (InMemoryWasmTestBuilder, ContractHash, ContractHash) {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let vault = install_vault(&mut builder, cep18_token);
+    (builder, vault, cep18_token)
+}
+
+fn make_vault_request(vault: ContractHash, entry_point: &str, args: RuntimeArgs) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(*DEFAULT_ACCOUNT_ADDR, vault, entry_point, args)
+        .build()
+}
+
+#[test]
+fn should_deposit_and_redeem_with_yield() {
+    let (mut builder, vault, underlying) = setup_vault();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let vault_key = get_cep18_package_key(&builder, vault);
+
+    builder
+        .exec(make_vault_request(
+            vault,
+            METHOD_DEPOSIT,
+            runtime_args! { ARG_AMOUNT => U256::from(DEPOSIT_AMOUNT) },
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &vault, owner),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &underlying, vault_key),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+
+    // Yield accrues to the vault, doubling the value of each share.
+    let yield_request =
+        make_cep18_transfer_request(owner, &underlying, vault_key, U256::from(DEPOSIT_AMOUNT));
+    builder.exec(yield_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_convert_to_assets(&mut builder, &vault, U256::from(DEPOSIT_AMOUNT)),
+        U256::from(1_999)
+    );
+
+    builder
+        .exec(make_vault_request(
+            vault,
+            METHOD_REDEEM,
+            runtime_args! { ARG_SHARES => U256::from(500) },
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &vault, owner),
+        U256::from(500)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &underlying, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY - 2 * DEPOSIT_AMOUNT + 999)
+    );
+}
+
+#[test]
+fn should_mint_shares_and_withdraw_assets() {
+    let (mut builder, vault, underlying) = setup_vault();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    builder
+        .exec(make_vault_request(
+            vault,
+            METHOD_MINT_SHARES,
+            runtime_args! { ARG_SHARES => U256::from(DEPOSIT_AMOUNT) },
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &underlying, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY - DEPOSIT_AMOUNT)
+    );
+
+    builder
+        .exec(make_vault_request(
+            vault,
+            METHOD_WITHDRAW,
+            runtime_args! { ARG_AMOUNT => U256::from(400) },
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &vault, owner),
+        U256::from(600)
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &vault),
+        U256::from(600)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &underlying, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY - 600)
+    );
+
+    builder
+        .exec(make_vault_request(
+            vault,
+            METHOD_DEPOSIT,
+            runtime_args! { ARG_AMOUNT => U256::zero() },
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_VAULT_AMOUNT);
+}
+
+#[test]
+fn should_mint_shares_for_received_assets() {
+    // The underlying token charges a 1% fee, so the vault receives less than the deposited amount.
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_FEE => 1u8,
+        TRANSFER_FEE_BPS => 100u32,
+        TRANSFER_FEE_TREASURY => Key::Account(*ACCOUNT_2_ADDR),
+    });
+    let vault = install_vault(&mut builder, cep18_token);
+    let vault_key = get_cep18_package_key(&builder, vault);
+
+    builder
+        .exec(make_vault_request(
+            vault,
+            METHOD_DEPOSIT,
+            runtime_args! { ARG_AMOUNT => U256::from(DEPOSIT_AMOUNT) },
+        ))
+        .expect_success()
+        .commit();
+
+    let received = cep18_check_balance_of(&mut builder, &cep18_token, vault_key);
+    assert!(received < U256::from(DEPOSIT_AMOUNT));
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &vault, Key::Account(*DEFAULT_ACCOUNT_ADDR)),
+        received
+    );
+}
+
+#[test]
+fn should_not_mint_shares_outside_vault_mode() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    builder
+        .exec(make_vault_request(
+            cep18_token,
+            METHOD_MINT_SHARES,
+            runtime_args! { ARG_SHARES => U256::from(DEPOSIT_AMOUNT) },
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_WRAPPER_DISABLED);
+}
+
+#[test]
+fn should_not_install_vault_with_initial_supply() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let underlying_package = get_cep18_package_key(&builder, cep18_token);
+
+    // Shares minted at installation have no assets behind them, so they would take a cut of the
+    // first deposits.
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => VAULT_TOKEN_NAME,
+            ARG_SYMBOL => "VLT",
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(DEPOSIT_AMOUNT),
+            WRAPPER_MODE => 2u8,
+            UNDERLYING_TOKEN => underlying_package,
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_WRAPPER_SUPPLY);
+}
@@ -0,0 +1,187 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_1_SECRET_KEY, ACCOUNT_2_ADDR,
+        ACCOUNT_2_PUBLIC_KEY, ACCOUNT_2_SECRET_KEY, ARG_AMOUNT, ARG_EXPIRY, ARG_NONCE,
+        ARG_RECIPIENT, ARG_SIGNATURE, ARG_SIGNER_PUBLIC_KEY, ENABLE_MINT_BURN,
+        ERROR_INVALID_VOUCHER_SIGNATURE, ERROR_INVALID_VOUCHER_SIGNER,
+        ERROR_VOUCHER_ALREADY_REDEEMED, ERROR_VOUCHER_EXPIRED, METHOD_MINT_WITH_VOUCHER,
+        MINTER_LIST, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_total_supply, get_cep18_package_key,
+        setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const VOUCHER_AMOUNT: u64 = 1_000;
+const VOUCHER_EXPIRY: u64 = 10_000;
+
+fn sign_voucher(
+    builder: &InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    secret_key: &SecretKey,
+    public_key: &PublicKey,
+    nonce: u64,
+) -> Bytes {
+    let bytes = (
+        get_cep18_package_key(builder, cep18_token),
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(VOUCHER_AMOUNT),
+        nonce,
+        VOUCHER_EXPIRY,
+    )
+        .to_bytes()
+        .unwrap();
+    let signature = crypto::sign(crypto::blake2b(bytes), secret_key, public_key);
+    Bytes::from(signature.to_bytes().unwrap())
+}
+
+fn make_voucher_request(
+    cep18_token: ContractHash,
+    signer_public_key: &PublicKey,
+    signature: Bytes,
+    nonce: u64,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_MINT_WITH_VOUCHER,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::from(VOUCHER_AMOUNT),
+            ARG_NONCE => nonce,
+            ARG_EXPIRY => VOUCHER_EXPIRY,
+            ARG_SIGNER_PUBLIC_KEY => signer_public_key.clone(),
+            ARG_SIGNATURE => signature,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+#[test]
+fn should_mint_with_voucher_once() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+    let signature = sign_voucher(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        1,
+    );
+
+    builder
+        .exec(make_voucher_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature.clone(),
+            1,
+            VOUCHER_EXPIRY,
+        ))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(VOUCHER_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(TOKEN_TOTAL_SUPPLY) + VOUCHER_AMOUNT
+    );
+
+    builder
+        .exec(make_voucher_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            1,
+            VOUCHER_EXPIRY,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_VOUCHER_ALREADY_REDEEMED);
+}
+
+#[test]
+fn should_not_mint_with_invalid_voucher() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![Key::Account(*ACCOUNT_1_ADDR)],
+    });
+
+    let signature = sign_voucher(
+        &builder,
+        cep18_token,
+        &ACCOUNT_2_SECRET_KEY,
+        &ACCOUNT_2_PUBLIC_KEY,
+        1,
+    );
+    builder
+        .exec(make_voucher_request(
+            cep18_token,
+            &ACCOUNT_2_PUBLIC_KEY,
+            signature.clone(),
+            1,
+            0,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_VOUCHER_SIGNER);
+
+    builder
+        .exec(make_voucher_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            1,
+            0,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_VOUCHER_SIGNATURE);
+
+    let signature = sign_voucher(
+        &builder,
+        cep18_token,
+        &ACCOUNT_1_SECRET_KEY,
+        &ACCOUNT_1_PUBLIC_KEY,
+        2,
+    );
+    builder
+        .exec(make_voucher_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature.clone(),
+            1,
+            0,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_INVALID_VOUCHER_SIGNATURE);
+
+    builder
+        .exec(make_voucher_request(
+            cep18_token,
+            &ACCOUNT_1_PUBLIC_KEY,
+            signature,
+            2,
+            VOUCHER_EXPIRY + 1,
+        ))
+        .commit();
+    assert_user_error(&builder, ERROR_VOUCHER_EXPIRED);
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::zero()
+    );
+}
@@ -0,0 +1,148 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+        CEP18_CONTRACT_WASM, ENABLE_MINT_BURN, ENABLE_TRANSFER_FEE, ERROR_INVALID_WRAPPER_SUPPLY,
+        ERROR_WRAPPER_DISABLED, ERROR_WRAPPER_INVARIANT_VIOLATED, METHOD_UNWRAP, METHOD_WRAP,
+        TOKEN_DECIMALS, TOKEN_TOTAL_SUPPLY, TRANSFER_FEE_BPS, TRANSFER_FEE_TREASURY,
+        UNDERLYING_TOKEN, WRAPPER_MODE,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_total_supply, get_cep18_package_key,
+        install_cep18_token, make_cep18_approve_request, setup, setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const WRAPPER_TOKEN_NAME: &str = "WrappedTest";
+const WRAP_AMOUNT: u64 = 1_000;
+const UNWRAP_AMOUNT: u64 = 400;
+
+/// Installs a 1:1 wrapper over `underlying`, and approves the wrapper to take the underlying
+/// tokens of the default account.
+fn install_wrapper(
+    builder: &mut InMemoryWasmTestBuilder,
+    underlying: ContractHash,
+) -> ContractHash {
+    let underlying_package = get_cep18_package_key(builder, underlying);
+    let wrapper = install_cep18_token(
+        builder,
+        runtime_args! {
+            ARG_NAME => WRAPPER_TOKEN_NAME,
+            ARG_SYMBOL => "WTST",
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::zero(),
+            WRAPPER_MODE => 3u8,
+            UNDERLYING_TOKEN => underlying_package,
+        },
+    );
+    let approve_request = make_cep18_approve_request(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        &underlying,
+        get_cep18_package_key(builder, wrapper),
+        U256::from(TOKEN_TOTAL_SUPPLY),
+    );
+    builder.exec(approve_request).expect_success().commit();
+    wrapper
+}
+
+fn make_wrapper_request(wrapper: ContractHash, entry_point: &str, amount: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        wrapper,
+        entry_point,
+        runtime_args! { ARG_AMOUNT => U256::from(amount) },
+    )
+    .build()
+}
+
+#[test]
+fn should_wrap_and_unwrap_cep18() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let wrapper = install_wrapper(&mut builder, cep18_token);
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let wrapper_key = get_cep18_package_key(&builder, wrapper);
+
+    builder
+        .exec(make_wrapper_request(wrapper, METHOD_WRAP, WRAP_AMOUNT))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &wrapper, owner),
+        U256::from(WRAP_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, wrapper_key),
+        U256::from(WRAP_AMOUNT)
+    );
+
+    builder
+        .exec(make_wrapper_request(wrapper, METHOD_UNWRAP, UNWRAP_AMOUNT))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &wrapper),
+        U256::from(WRAP_AMOUNT - UNWRAP_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, wrapper_key),
+        U256::from(WRAP_AMOUNT - UNWRAP_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY - WRAP_AMOUNT + UNWRAP_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_wrap_underlying_charging_transfer_fees() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ENABLE_TRANSFER_FEE => 1u8,
+        TRANSFER_FEE_BPS => 100u32,
+        TRANSFER_FEE_TREASURY => Key::Account(*ACCOUNT_2_ADDR),
+    });
+    let wrapper = install_wrapper(&mut builder, cep18_token);
+
+    builder
+        .exec(make_wrapper_request(wrapper, METHOD_WRAP, WRAP_AMOUNT))
+        .commit();
+    assert_user_error(&builder, ERROR_WRAPPER_INVARIANT_VIOLATED);
+}
+
+#[test]
+fn should_not_wrap_outside_cep18_wrapper_mode() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+
+    builder
+        .exec(make_wrapper_request(cep18_token, METHOD_WRAP, WRAP_AMOUNT))
+        .commit();
+    assert_user_error(&builder, ERROR_WRAPPER_DISABLED);
+}
+
+#[test]
+fn should_not_install_cep18_wrapper_with_mint_and_burn() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup();
+    let underlying_package = get_cep18_package_key(&builder, cep18_token);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => WRAPPER_TOKEN_NAME,
+            ARG_SYMBOL => "WTST",
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::zero(),
+            ENABLE_MINT_BURN => 1u8,
+            WRAPPER_MODE => 3u8,
+            UNDERLYING_TOKEN => underlying_package,
+        },
+    )
+    .build();
+    builder.exec(install_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_WRAPPER_SUPPLY);
+}
@@ -0,0 +1,230 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT,
+};
+use casper_types::{
+    runtime_args, system::mint, ContractHash, ContractPackageHash, Key, RuntimeArgs, URef, U256,
+    U512,
+};
+
+use crate::utility::{
+    constants::{
+        ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_SYMBOL, ARG_TOKEN_CONTRACT, ARG_TOTAL_SUPPLY,
+        CEP18_CONTRACT_WASM, ENABLE_MINT_BURN, ENABLE_REBASING, ERROR_INSUFFICIENT_BALANCE,
+        ERROR_INVALID_WRAPPER_SUPPLY, ERROR_WRAPPER_DISABLED, METHOD_DEPOSIT_AS_STORED_CONTRACT,
+        METHOD_WITHDRAW, TEST_CONTRACT_PURSE_KEY, TOKEN_SYMBOL, WRAPPER_MODE, WRAPPER_PURSE,
+    },
+    installer_request_builders::{
+        assert_user_error, cep18_check_balance_of, cep18_check_total_supply,
+        make_cep18_transfer_request, setup, setup_with_modalities, TestContext,
+    },
+};
+
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+
+const DEPOSIT_AMOUNT: u64 = 5_000_000_000;
+const WITHDRAW_AMOUNT: u64 = 2_000_000_000;
+
+fn get_purse(builder: &InMemoryWasmTestBuilder, contract_hash: ContractHash, name: &str) -> URef {
+    builder
+        .get_contract(contract_hash)
+        .expect("should have contract")
+        .named_keys()
+        .get(name)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have purse")
+}
+
+/// Funds the purse of the test contract, which then deposits `amount` motes as a contract caller.
+fn deposit_as_test_contract(
+    builder: &mut InMemoryWasmTestBuilder,
+    cep18_token: ContractHash,
+    cep18_test_contract_package: ContractPackageHash,
+    amount: U256,
+) -> ExecuteRequest {
+    let test_contract_hash = *builder
+        .get_contract_package(cep18_test_contract_package)
+        .expect("should have contract package")
+        .enabled_versions()
+        .values()
+        .next_back()
+        .expect("should have latest version");
+    let test_contract_purse = get_purse(builder, test_contract_hash, TEST_CONTRACT_PURSE_KEY);
+
+    let id: Option<u64> = None;
+    let fund_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => test_contract_purse,
+            mint::ARG_AMOUNT => U512::from(DEPOSIT_AMOUNT),
+            mint::ARG_ID => id,
+        },
+    )
+    .build();
+    builder.exec(fund_request).expect_success().commit();
+
+    ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_test_contract_package,
+        None,
+        METHOD_DEPOSIT_AS_STORED_CONTRACT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(cep18_token),
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_wrap_and_unwrap_cspr() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup_with_modalities(runtime_args! {
+        ARG_DECIMALS => 9u8,
+        ARG_TOTAL_SUPPLY => U256::zero(),
+        WRAPPER_MODE => 1u8,
+    });
+    let test_contract_key = Key::from(cep18_test_contract_package);
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let wrapper_purse = get_purse(&builder, cep18_token, WRAPPER_PURSE);
+
+    let deposit_request = deposit_as_test_contract(
+        &mut builder,
+        cep18_token,
+        cep18_test_contract_package,
+        U256::from(DEPOSIT_AMOUNT),
+    );
+    builder.exec(deposit_request).expect_success().commit();
+
+    assert_eq!(
+        builder.get_purse_balance(wrapper_purse),
+        U512::from(DEPOSIT_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, test_contract_key),
+        U256::from(DEPOSIT_AMOUNT)
+    );
+
+    let transfer_request = make_cep18_transfer_request(
+        test_contract_key,
+        &cep18_token,
+        owner,
+        U256::from(WITHDRAW_AMOUNT),
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let main_purse_balance_before = builder.get_purse_balance(
+        builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .main_purse(),
+    );
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(WITHDRAW_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(withdraw_request).expect_success().commit();
+
+    let main_purse_balance_after = builder.get_purse_balance(
+        builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .main_purse(),
+    );
+    assert_eq!(
+        main_purse_balance_after,
+        main_purse_balance_before + WITHDRAW_AMOUNT - *DEFAULT_PAYMENT
+    );
+    assert_eq!(
+        builder.get_purse_balance(wrapper_purse),
+        U512::from(DEPOSIT_AMOUNT - WITHDRAW_AMOUNT)
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_token, owner),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_token),
+        U256::from(DEPOSIT_AMOUNT - WITHDRAW_AMOUNT)
+    );
+}
+
+#[test]
+fn should_not_withdraw_more_than_wrapped() {
+    let (mut builder, TestContext { cep18_token, .. }) = setup_with_modalities(runtime_args! {
+        ARG_DECIMALS => 9u8,
+        ARG_TOTAL_SUPPLY => U256::zero(),
+        WRAPPER_MODE => 1u8,
+    });
+
+    let withdraw_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        METHOD_WITHDRAW,
+        runtime_args! {
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(withdraw_request).commit();
+
+    assert_user_error(&builder, ERROR_INSUFFICIENT_BALANCE);
+}
+
+#[test]
+fn should_not_deposit_with_wrapper_disabled() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_token,
+            cep18_test_contract_package,
+        },
+    ) = setup();
+
+    let deposit_request = deposit_as_test_contract(
+        &mut builder,
+        cep18_token,
+        cep18_test_contract_package,
+        U256::from(DEPOSIT_AMOUNT),
+    );
+    builder.exec(deposit_request).commit();
+
+    assert_user_error(&builder, ERROR_WRAPPER_DISABLED);
+}
+
+#[test]
+fn should_not_install_wrapped_cspr_with_unbacked_supply() {
+    let (mut builder, _) = setup();
+
+    for (total_supply, enable_mint_burn, enable_rebasing) in [
+        (U256::from(DEPOSIT_AMOUNT), 0u8, 0u8),
+        (U256::zero(), 1u8, 0u8),
+        (U256::zero(), 0u8, 1u8),
+    ] {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CEP18_CONTRACT_WASM,
+            runtime_args! {
+                ARG_NAME => "WrappedCsprTest",
+                ARG_SYMBOL => TOKEN_SYMBOL,
+                ARG_DECIMALS => 9u8,
+                ARG_TOTAL_SUPPLY => total_supply,
+                ENABLE_MINT_BURN => enable_mint_burn,
+                ENABLE_REBASING => enable_rebasing,
+                WRAPPER_MODE => 1u8,
+            },
+        )
+        .build();
+        builder.exec(install_request).commit();
+        assert_user_error(&builder, ERROR_INVALID_WRAPPER_SUPPLY);
+    }
+}